@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::error::{expect_prefix, parse_number, split_once};
+use crate::{Answer, Day, ParseError};
+
+/// `answers.toml` at the root of the crate, next to the inputs.
+pub fn answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+/// Known answers of the puzzles, read from a small subset of TOML:
+///
+/// ```toml
+/// [2023.day10b]
+/// part1 = 6856
+/// part2 = "some text"
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    expected: HashMap<(u32, String, usize), Answer>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_unlocated(input).map_err(|err| err.locate(input))
+    }
+
+    fn parse_unlocated(input: &str) -> Result<Self, ParseError> {
+        let mut expected = HashMap::new();
+        let mut day = None;
+
+        for line in input.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| ParseError::new("expected `]`", &header[header.len()..]))?;
+                let (year, day_name) = split_once(header, ".")?;
+                day = Some((parse_number::<u32>(year)?, expect_prefix(day_name, "day")?));
+                continue;
+            }
+
+            let Some((year, day)) = day else {
+                return Err(ParseError::new("expected a `[<year>.dayN]` header first", line));
+            };
+            let (key, value) = split_once(line, "=")?;
+            let part = parse_number::<usize>(expect_prefix(key.trim(), "part")?)?;
+            let value = value.trim();
+            let answer = match value.strip_prefix('"') {
+                Some(text) => text
+                    .strip_suffix('"')
+                    .ok_or_else(|| ParseError::new("unterminated string", value))?
+                    .into(),
+                None => Answer::Number(parse_number(value)?),
+            };
+            if expected.insert((year, day.to_string(), part), answer).is_some() {
+                return Err(ParseError::new("duplicate answer", key.trim()));
+            }
+        }
+
+        Ok(Self { expected })
+    }
+
+    /// `day` is the name of the day on the command line, e.g. `10b`.
+    pub fn get(&self, year: u32, day: &str, part: usize) -> Option<&Answer> {
+        self.expected.get(&(year, day.to_string(), part))
+    }
+
+    /// Solves the given parts of a day, timing them, and compares them with the expected answers.
+    ///
+    /// An unreadable or malformed input, as well as a panicking part, counts as a failure.
+    pub fn check(&self, day: &Day, input: io::Result<String>, parts: &[usize]) -> Vec<Check> {
+        let start = Instant::now();
+        let solver = input.map_err(|err| err.to_string()).and_then(|input| {
+            day.parse(&input).map_err(|err| err.to_string())
+        });
+        let parse_time = start.elapsed();
+
+        let mut solver = match solver {
+            Ok(solver) => Some(solver),
+            Err(err) => {
+                eprintln!("Cannot load day {}: {}", day.name(), err);
+                None
+            },
+        };
+
+        parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = solver.as_mut().and_then(|solver| {
+                    panic::catch_unwind(AssertUnwindSafe(|| solver.part(part))).ok()
+                });
+                let time = answer.as_ref().map(|_| start.elapsed());
+
+                let expected = self.get(day.year, &day.name(), part).cloned();
+                let status = Status::of(answer.as_ref(), expected.as_ref());
+                Check { year: day.year, day: day.day, variant: day.variant, part, answer, expected, status, parse_time, time }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Mismatch,
+    /// The input could not be loaded or the part panicked.
+    Fail,
+    Skipped,
+    /// No expected answer to compare with.
+    Unchecked,
+}
+
+impl Status {
+    fn of(answer: Option<&Answer>, expected: Option<&Answer>) -> Self {
+        match (answer, expected) {
+            (None, _) => Status::Fail,
+            (Some(Answer::Skipped(_)), _) => Status::Skipped,
+            (Some(_), None) => Status::Unchecked,
+            (Some(answer), Some(expected)) if answer == expected => Status::Pass,
+            _ => Status::Mismatch,
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Mismatch | Status::Fail)
+    }
+
+    /// Lowercase name, for machine-readable output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Mismatch => "mismatch",
+            Status::Fail => "fail",
+            Status::Skipped => "skipped",
+            Status::Unchecked => "unchecked",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_failure() {
+            f.pad(&self.name().to_uppercase())
+        } else {
+            f.pad(self.name())
+        }
+    }
+}
+
+/// Outcome of one part: its answer, timings and how it compares with the expected answer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub year: u32,
+    pub day: usize,
+    pub variant: Option<&'static str>,
+    pub part: usize,
+    pub answer: Option<Answer>,
+    pub expected: Option<Answer>,
+    pub status: Status,
+    /// Time spent parsing the input, shared by the parts of a day.
+    pub parse_time: Duration,
+    /// `None` when the part did not complete.
+    pub time: Option<Duration>,
+}
+
+impl Check {
+    /// Name used on the command line, e.g. `10` or `10b`.
+    pub fn name(&self) -> String {
+        format!("{}{}", self.day, self.variant.unwrap_or(""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(input: &str) -> (usize, usize, String) {
+        let err = Answers::parse(input).unwrap_err();
+        (err.line, err.column, err.message)
+    }
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse(
+            "# Known answers\n\n[2023.day10b]\n  # indented comment\npart1 = 6856\npart2=\"some text\"\n\n[2023.day25]\npart1 = -3\npart2 = \"\"\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(2023, "10b", 1), Some(&Answer::Number(6856)));
+        assert_eq!(answers.get(2023, "10b", 2), Some(&"some text".into()));
+        assert_eq!(answers.get(2023, "25", 1), Some(&Answer::Number(-3)));
+        assert_eq!(answers.get(2023, "25", 2), Some(&"".into()));
+        assert_eq!(answers.get(2023, "10", 1), None);
+        assert_eq!(answers.get(2022, "10b", 1), None);
+        assert_eq!(answers.get(2023, "10b", 3), None);
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(error("part1 = 1"), (1, 1, "expected a `[<year>.dayN]` header first".into()));
+        assert_eq!(error("[2023.day1"), (1, 11, "expected `]`".into()));
+        assert_eq!(error("[2023-day1]"), (1, 2, "expected `.`".into()));
+        assert_eq!(error("[2023.1]"), (1, 7, "expected `day`".into()));
+        assert_eq!(error("[2023.day1]\npart1 1"), (2, 1, "expected `=`".into()));
+        assert_eq!(error("[2023.day1]\nanswer = 1"), (2, 1, "expected `part`".into()));
+        assert_eq!(error("[2023.day1]\npart1 = 12x"), (2, 9, "invalid number".into()));
+        assert_eq!(error("[2023.day1]\npart1 = \"text"), (2, 9, "unterminated string".into()));
+        assert_eq!(error("[2023.day1]\npart1 = 1\n\n[2023.day1]\npart1 = 2"), (5, 1, "duplicate answer".into()));
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::{parse_number, split_once};
+use crate::{Day, ParseError};
+
+/// Steps measured for every day, in the order they run.
+pub const STEPS: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarizes a non-empty set of samples, using the nearest-rank method for percentiles.
+    pub fn of(samples: &[Duration]) -> Self {
+        let mut samples = samples.to_vec();
+        samples.sort();
+        let rank = |percentile: usize| samples[(samples.len() * percentile).div_ceil(100).max(1) - 1];
+
+        Self {
+            min: samples[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+}
+
+/// Statistics of one step of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: String,
+    pub step: String,
+    pub stats: Stats,
+}
+
+/// Parses the input and solves both parts `warmup + samples` times, a fresh solution each time,
+/// and keeps the timings of the last `samples` runs.
+pub fn measure(day: &Day, input: &str, warmup: usize, samples: usize) -> Result<Vec<Measurement>, ParseError> {
+    let mut timings = vec![Vec::with_capacity(samples); STEPS.len()];
+
+    for run in 0..warmup + samples {
+        let start = Instant::now();
+        let mut solver = day.parse(input)?;
+        let mut times = vec![start.elapsed()];
+
+        for part in [1, 2] {
+            let start = Instant::now();
+            black_box(solver.part(part));
+            times.push(start.elapsed());
+        }
+
+        if run >= warmup {
+            for (timing, time) in timings.iter_mut().zip(times) {
+                timing.push(time);
+            }
+        }
+    }
+
+    Ok(STEPS
+        .iter()
+        .zip(timings)
+        .map(|(step, timing)| Measurement { day: day.name(), step: step.to_string(), stats: Stats::of(&timing) })
+        .collect())
+}
+
+/// Saved measurements, one `day,step,min,median,p95` line per step with durations in nanoseconds.
+#[derive(Debug, Clone, Default)]
+pub struct Baseline {
+    stats: HashMap<(String, String), Stats>,
+}
+
+impl Baseline {
+    pub fn new(measurements: &[Measurement]) -> Self {
+        Self {
+            stats: measurements
+                .iter()
+                .map(|measurement| ((measurement.day.clone(), measurement.step.clone()), measurement.stats))
+                .collect(),
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_unlocated(input).map_err(|err| err.locate(input))
+    }
+
+    fn parse_unlocated(input: &str) -> Result<Self, ParseError> {
+        let mut stats = HashMap::new();
+        for line in input.lines().filter(|line| !line.is_empty() && !line.starts_with('#')) {
+            let (day, rest) = split_once(line, ",")?;
+            let (step, rest) = split_once(rest, ",")?;
+            let (min, rest) = split_once(rest, ",")?;
+            let (median, p95) = split_once(rest, ",")?;
+
+            let nanos = |text| parse_number(text).map(Duration::from_nanos);
+            stats.insert(
+                (day.to_string(), step.to_string()),
+                Stats { min: nanos(min)?, median: nanos(median)?, p95: nanos(p95)? },
+            );
+        }
+
+        Ok(Self { stats })
+    }
+
+    pub fn get(&self, day: &str, step: &str) -> Option<&Stats> {
+        self.stats.get(&(day.to_string(), step.to_string()))
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day,step,min,median,p95 (ns)")?;
+
+        let mut keys = self.stats.keys().collect::<Vec<_>>();
+        keys.sort_by_key(|(day, step)| (day.trim_end_matches(char::is_alphabetic).parse::<usize>().ok(), day.clone(), step.clone()));
+        for key in keys {
+            let stats = &self.stats[key];
+            writeln!(
+                f,
+                "{},{},{},{},{}",
+                key.0, key.1, stats.min.as_nanos(), stats.median.as_nanos(), stats.p95.as_nanos(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Relative change of the median against the baseline, e.g. `0.1` when 10% slower.
+pub fn change(stats: &Stats, baseline: &Stats) -> f64 {
+    stats.median.as_secs_f64() / baseline.median.as_secs_f64().max(f64::MIN_POSITIVE) - 1.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(millis: &[u64]) -> (u64, u64, u64) {
+        let stats = Stats::of(&millis.iter().map(|&ms| Duration::from_millis(ms)).collect::<Vec<_>>());
+        (stats.min.as_millis() as u64, stats.median.as_millis() as u64, stats.p95.as_millis() as u64)
+    }
+
+    #[test]
+    fn nearest_rank() {
+        assert_eq!(stats(&[7]), (7, 7, 7));
+        assert_eq!(stats(&[5, 1, 3, 2, 4]), (1, 3, 5));
+        assert_eq!(stats(&[4, 1, 3, 2]), (1, 2, 4));
+        assert_eq!(stats(&(1..=20).rev().collect::<Vec<_>>()), (1, 10, 19));
+        assert_eq!(stats(&(1..=100).collect::<Vec<_>>()), (1, 50, 95));
+    }
+
+    #[test]
+    fn baseline() {
+        let input = "# day,step,min,median,p95 (ns)\n10b,part2,100,150,300\n\n2,parse,5,6,7\n10b,parse,1,2,3\n";
+        let baseline = Baseline::parse(input).unwrap();
+        let part2 = baseline.get("10b", "part2").unwrap();
+        assert_eq!((part2.min, part2.median, part2.p95), (Duration::from_nanos(100), Duration::from_nanos(150), Duration::from_nanos(300)));
+        assert_eq!(baseline.get("10", "part2"), None);
+        assert_eq!(baseline.to_string(), "# day,step,min,median,p95 (ns)\n2,parse,5,6,7\n10b,parse,1,2,3\n10b,part2,100,150,300\n");
+        assert_eq!(Baseline::parse(&baseline.to_string()).unwrap().to_string(), baseline.to_string());
+
+        let slower = Stats { median: Duration::from_nanos(165), ..*part2 };
+        assert!((change(&slower, part2) - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn malformed_baseline() {
+        let error = |input: &str| {
+            let err = Baseline::parse(input).unwrap_err();
+            (err.line, err.column, err.message)
+        };
+        assert_eq!(error("1,parse,1,2"), (1, 11, "expected `,`".into()));
+        assert_eq!(error("1,parse,1,2,3\n1,part1,1,x,3"), (2, 11, "invalid number".into()));
+    }
+}
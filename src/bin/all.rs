@@ -1,55 +1,167 @@
-#![allow(dead_code)]
-
-use advent::day1;
-use advent::day2;
-use advent::day3;
-use advent::day4;
-use advent::day5;
-use advent::day6;
-use advent::day7;
-use advent::day8;
-use advent::day9;
-use advent::day10;
-use advent::day10b;
-use advent::day11;
-use advent::day12;
-use advent::day13;
-use advent::day14;
-use advent::day15;
-use advent::day16;
-use advent::day17;
-use advent::day18;
-use advent::day19;
-use advent::day20;
-use advent::day21;
-use advent::day22;
-use advent::day23;
-use advent::day24;
+use std::env;
+use std::fs::read_to_string;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process;
+use std::sync::{mpsc, Arc};
+use std::time::Instant;
+
+use threadpool::ThreadPool;
+
+use advent::answers::{answers_path, Answers};
+use advent::format::Format;
+use advent::input::{input_path, inputs_dir};
+use advent::report::summary;
+use advent::select::DaySet;
+use advent::{latest_year, Day, DAYS};
+
+const USAGE: &str = "Usage: all [--year <year>] [--input <dir>] [--only <days>] [--skip <days>] [--jobs <n>]
+           [--check [--answers <file>]] [--format <table|json|csv>]
+<year> defaults to the most recent year with solutions
+<days> is a list of days or inclusive ranges, e.g. `5,22` or `10..15`";
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn days(arg: Option<String>) -> DaySet {
+    let arg = arg.unwrap_or_else(|| usage());
+    arg.parse().unwrap_or_else(|err| fail(format!("Invalid days `{}`: {}", arg, err)))
+}
+
+/// Runs `task` on every day using `jobs` threads, and hands the results to `consume` in day order.
+/// A panicking task yields `None`.
+fn run_days<T, F>(days: &[&'static Day], jobs: usize, task: F, mut consume: impl FnMut(&Day, Option<T>))
+where
+    T: Send + 'static,
+    F: Fn(&'static Day) -> T + Send + Sync + 'static,
+{
+    let pool = ThreadPool::new(jobs);
+    let task = Arc::new(task);
+    let (tx, rx) = mpsc::channel();
+
+    for (index, &day) in days.iter().enumerate() {
+        let task = task.clone();
+        let tx = tx.clone();
+        pool.execute(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| task(day))).ok();
+            let _ = tx.send((index, result));
+        });
+    }
+    drop(tx);
+
+    // Hold back the results of the days finishing before the ones preceding them
+    let mut pending = (0..days.len()).map(|_| None).collect::<Vec<_>>();
+    let mut next = 0;
+    for (index, result) in rx {
+        pending[index] = Some(result);
+        while let Some(result) = pending.get_mut(next).and_then(Option::take) {
+            consume(days[next], result);
+            next += 1;
+        }
+    }
+}
 
 fn main() {
-    day1::Solution::init().solve();
-    day2::Solution::init().solve();
-    day3::Solution::init().solve();
-    day4::Solution::init().solve();
-    day5::Solution::init().solve();
-    day6::Solution::init().solve();
-    day7::Solution::init().solve();
-    day8::Solution::init().solve();
-    day9::Solution::init().solve();
-    day10::Solution::init().solve();
-    day10b::Solution::init().solve();
-    day11::Solution::init().solve();
-    day12::Solution::init().solve();
-    day13::Solution::init().solve();
-    day14::Solution::init().solve();
-    day15::Solution::init().solve();
-    day16::Solution::init().solve();
-    day17::Solution::init().solve();
-    day18::Solution::init().solve();
-    day19::Solution::init().solve();
-    day20::Solution::init().solve();
-    day21::Solution::init().solve();
-    day22::Solution::init().solve();
-    day23::Solution::init().solve();
-    day24::Solution::init().solve();
+    let mut args = env::args().skip(1);
+    let mut year = latest_year();
+    let mut dir = None;
+    let mut only = None;
+    let mut skip = DaySet::default();
+    let mut check = false;
+    let mut answers_file = answers_path();
+    let mut jobs = 1;
+    let mut format = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-y" | "--year" => year = args.next().and_then(|year| year.parse().ok()).unwrap_or_else(|| usage()),
+            "-i" | "--input" => dir = Some(PathBuf::from(args.next().unwrap_or_else(|| usage()))),
+            "-o" | "--only" => only = Some(days(args.next())),
+            "-s" | "--skip" => skip = days(args.next()),
+            "-j" | "--jobs" => {
+                jobs = match args.next().and_then(|jobs| jobs.parse().ok()) {
+                    Some(jobs) if jobs > 0 => jobs,
+                    _ => usage(),
+                }
+            },
+            "-c" | "--check" => check = true,
+            "-a" | "--answers" => answers_file = PathBuf::from(args.next().unwrap_or_else(|| usage())),
+            "-f" | "--format" => {
+                let arg = args.next().unwrap_or_else(|| usage());
+                format = Some(arg.parse::<Format>().unwrap_or_else(|err| fail(format!("Invalid format `{}`: {}", arg, err))));
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            },
+            _ => usage(),
+        }
+    }
+
+    let dir = dir.unwrap_or_else(|| inputs_dir(year));
+    let days = DAYS
+        .iter()
+        .filter(|day| day.year == year)
+        .filter(|day| only.as_ref().is_none_or(|only: &DaySet| only.contains(day.day)) && !skip.contains(day.day))
+        .collect::<Vec<_>>();
+    let start = Instant::now();
+
+    if check || format.is_some() {
+        let answers = if check {
+            let content = read_to_string(&answers_file)
+                .unwrap_or_else(|err| fail(format!("Cannot read answers {}: {}", answers_file.display(), err)));
+            Answers::parse(&content)
+                .unwrap_or_else(|err| fail(format!("Cannot parse answers {}: {}", answers_file.display(), err)))
+        } else {
+            Answers::default()
+        };
+
+        let mut checks = Vec::new();
+        run_days(
+            &days,
+            jobs,
+            move |day| answers.check(day, read_to_string(input_path(&dir, day.day)), &[1, 2]),
+            |_, result| checks.extend(result.expect("checks catch the panics of the solutions")),
+        );
+
+        let format = format.unwrap_or_default();
+        print!("{}", format.render(&checks));
+        if format == Format::Table {
+            println!("Total wall time: {:.2?}", start.elapsed());
+        }
+        if checks.iter().any(|check| check.status.is_failure()) {
+            process::exit(1);
+        }
+    } else {
+        let mut reports = Vec::new();
+        run_days(
+            &days,
+            jobs,
+            move |day| {
+                let path = input_path(&dir, day.day);
+                let input = read_to_string(&path)
+                    .map_err(|err| format!("Cannot read input {}: {}", path.display(), err))?;
+                day.solve(&input, &[1, 2])
+                    .map_err(|err| format!("Cannot parse input of day {}: {}", day.name(), err))
+            },
+            |day, result| match result {
+                Some(Ok(report)) => {
+                    print!("{}", report);
+                    reports.push(report);
+                },
+                Some(Err(message)) => fail(message),
+                None => fail(format!("Day {} panicked", day.name())),
+            },
+        );
+
+        print!("{}", summary(&reports));
+        println!("\nTotal wall time: {:.2?}", start.elapsed());
+    }
 }
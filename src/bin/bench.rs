@@ -0,0 +1,117 @@
+use std::env;
+use std::fs::{read_to_string, write};
+use std::path::PathBuf;
+use std::process;
+
+use advent::bench::{change, measure, Baseline};
+use advent::input::{input_path, inputs_dir};
+use advent::select::DaySet;
+use advent::{latest_year, DAYS};
+
+const USAGE: &str = "Usage: bench [--year <year>] [--input <dir>] [--only <days>] [--skip <days>] [--warmup <n>]
+             [--samples <n>] [--save <file>] [--compare <file> [--threshold <percent>]]
+<year> defaults to the most recent year with solutions
+<days> is a list of days or inclusive ranges, e.g. `5,22` or `10..15`";
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn days(arg: Option<String>) -> DaySet {
+    let arg = arg.unwrap_or_else(|| usage());
+    arg.parse().unwrap_or_else(|err| fail(format!("Invalid days `{}`: {}", arg, err)))
+}
+
+fn number<T: std::str::FromStr>(arg: Option<String>) -> T {
+    arg.and_then(|arg| arg.parse().ok()).unwrap_or_else(|| usage())
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let mut year = latest_year();
+    let mut dir = None;
+    let mut only = None;
+    let mut skip = DaySet::default();
+    let mut warmup = 2;
+    let mut samples = 10;
+    let mut save = None;
+    let mut compare = None;
+    let mut threshold = 10.;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-y" | "--year" => year = args.next().and_then(|year| year.parse().ok()).unwrap_or_else(|| usage()),
+            "-i" | "--input" => dir = Some(PathBuf::from(args.next().unwrap_or_else(|| usage()))),
+            "-o" | "--only" => only = Some(days(args.next())),
+            "-s" | "--skip" => skip = days(args.next()),
+            "-w" | "--warmup" => warmup = number(args.next()),
+            "-n" | "--samples" => samples = number(args.next()),
+            "--save" => save = Some(PathBuf::from(args.next().unwrap_or_else(|| usage()))),
+            "--compare" => compare = Some(PathBuf::from(args.next().unwrap_or_else(|| usage()))),
+            "-t" | "--threshold" => threshold = number(args.next()),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            },
+            _ => usage(),
+        }
+    }
+    if samples == 0 {
+        usage();
+    }
+
+    let baseline = compare.map(|path| {
+        let content = read_to_string(&path)
+            .unwrap_or_else(|err| fail(format!("Cannot read baseline {}: {}", path.display(), err)));
+        Baseline::parse(&content).unwrap_or_else(|err| fail(format!("Cannot parse baseline {}: {}", path.display(), err)))
+    });
+
+    println!(
+        "{:>4}  {:<5}  {:>10}  {:>10}  {:>10}{}",
+        "Day", "Step", "Min", "Median", "P95",
+        if baseline.is_some() { format!("  {:>10}  {:>8}", "Baseline", "Change") } else { String::new() },
+    );
+
+    let dir = dir.unwrap_or_else(|| inputs_dir(year));
+    let mut measurements = Vec::new();
+    let mut regressions = 0;
+    for day in DAYS.iter().filter(|day| day.year == year && only.as_ref().is_none_or(|only: &DaySet| only.contains(day.day)) && !skip.contains(day.day)) {
+        let path = input_path(&dir, day.day);
+        let input = read_to_string(&path)
+            .unwrap_or_else(|err| fail(format!("Cannot read input {}: {}", path.display(), err)));
+        let results = measure(day, &input, warmup, samples)
+            .unwrap_or_else(|err| fail(format!("Cannot parse input of day {}: {}", day.name(), err)));
+
+        for measurement in results {
+            let stats = &measurement.stats;
+            print!("{:>4}  {:<5}  {:>10.2?}  {:>10.2?}  {:>10.2?}", measurement.day, measurement.step, stats.min, stats.median, stats.p95);
+
+            match baseline.as_ref().map(|baseline| baseline.get(&measurement.day, &measurement.step)) {
+                Some(Some(reference)) => {
+                    let change = change(stats, reference) * 100.;
+                    let regressed = change > threshold;
+                    regressions += regressed as usize;
+                    println!("  {:>10.2?}  {:>+7.1}%{}", reference.median, change, if regressed { "  REGRESSION" } else { "" });
+                },
+                Some(None) => println!("  {:>10}  {:>8}", "-", "new"),
+                None => println!(),
+            }
+            measurements.push(measurement);
+        }
+    }
+
+    if let Some(path) = save {
+        write(&path, Baseline::new(&measurements).to_string())
+            .unwrap_or_else(|err| fail(format!("Cannot write baseline {}: {}", path.display(), err)));
+    }
+
+    if regressions > 0 {
+        fail(format!("\n{} step(s) slower than the baseline by more than {}%", regressions, threshold));
+    }
+}
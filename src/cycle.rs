@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Shape of the sequence `start, step(start), step(step(start)), ...` of a deterministic
+/// simulation: the states after `prefix` steps repeat every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Smallest number of steps reaching the same state as `n` steps.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.length
+        }
+    }
+}
+
+/// Brent's algorithm: compares states without storing them, stepping less often than [`floyd`].
+pub fn brent<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the length by moving the hare ahead of a tortoise teleported at each power of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Then the prefix, with the hare `length` steps ahead of the tortoise
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, length }
+}
+
+/// Floyd's tortoise and hare: compares states without storing them.
+pub fn floyd<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // The hare moves twice as fast, they meet at a multiple of the length once in the cycle
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut prefix = 0;
+    let mut tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut length = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { prefix, length }
+}
+
+/// Every state of a simulation until it repeats, found by hashing them: steps the least, at the
+/// cost of keeping each state.
+#[derive(Debug, Clone)]
+pub struct History<S> {
+    states: Vec<S>,
+    cycle: Cycle,
+}
+
+impl<S> History<S> {
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// State after `n` steps.
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.states[self.cycle.reduce(n)]
+    }
+}
+
+pub fn hashed<S: Clone + Eq + Hash>(start: S, mut step: impl FnMut(&S) -> S) -> History<S> {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = start;
+    let prefix = loop {
+        if let Some(&prefix) = seen.get(&state) {
+            break prefix;
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    };
+
+    let length = states.len() - prefix;
+    History { states, cycle: Cycle { prefix, length } }
+}
+
+/// State after `n` steps, found with [`brent`] when `n` is past the first cycle.
+pub fn state_at<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let cycle = brent(start.clone(), &mut step);
+    (0..cycle.reduce(n)).fold(start, |state, _| step(&state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, ..., 6, then 3, 4, 5, 6 over and over
+    fn step(&state: &usize) -> usize {
+        if state == 6 {
+            3
+        } else {
+            state + 1
+        }
+    }
+
+    #[test]
+    fn cycles() {
+        let expected = Cycle { prefix: 3, length: 4 };
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(hashed(0, step).cycle(), expected);
+        assert_eq!(brent(5, step), Cycle { prefix: 0, length: 4 });
+        assert_eq!(floyd(7, |_| 7), Cycle { prefix: 0, length: 1 });
+    }
+
+    #[test]
+    fn states() {
+        let history = hashed(0, step);
+        for n in [0, 2, 3, 6, 7, 1_000_000_001] {
+            let expected = (0..n.min(100 + n % 4)).fold(0, |state, _| step(&state));
+            assert_eq!(*history.state_at(n), expected);
+            assert_eq!(state_at(0, step, n), expected);
+        }
+    }
+}
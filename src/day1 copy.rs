@@ -1,48 +0,0 @@
-use std::fs::read_to_string;
-use std::io::{self, Write};
-
-use itertools::Itertools;
-
-pub struct Solution {
-    lines: Vec<String>,
-}
-
-impl Solution {
-    pub fn init() -> Self {
-        let mut lines = Vec::new();
-        for line in read_to_string("inputs/dayREPLACEDAY.txt").unwrap().lines() {
-            lines.push(line.to_string());
-        }
-
-        Self {
-            lines,
-        }
-    }
-
-    fn part1(&mut self) {
-
-    }
-
-    fn part2(&mut self) {
-
-    }
-
-    pub fn solve(&mut self) {
-        println!("========= DAY REPLACEDAY ========");
-        print!("Solving part 1: ");
-        io::stdout().flush().unwrap();
-
-        let start = std::time::Instant::now();
-        let part1 = self.part1();
-        let part1_time = start.elapsed();
-        println!("{:?} (took {:?})", part1, part1_time);
-
-        print!("Solving part 2: ");
-        io::stdout().flush().unwrap();
-        let start = std::time::Instant::now();
-        let part2 = self.part2();
-        let part2_time = start.elapsed();
-        println!("{:?} (took {:?})", part2, part2_time);
-        println!();
-    }
-}
\ No newline at end of file
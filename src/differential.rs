@@ -0,0 +1,64 @@
+use std::fmt::{self, Debug, Display};
+
+use itertools::Itertools;
+
+/// Input on which a fast implementation and its naive reference disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement<I, T> {
+    pub input: I,
+    pub fast: T,
+    pub naive: T,
+}
+
+impl<I: Debug, T: Debug> Display for Disagreement<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "fast gave {:?} but naive gave {:?} on {:?}", self.fast, self.naive, self.input)
+    }
+}
+
+/// Runs both implementations on every input, keeping those they disagree on.
+pub fn compare<I, T: PartialEq>(
+    inputs: impl IntoIterator<Item = I>,
+    mut fast: impl FnMut(&I) -> T,
+    mut naive: impl FnMut(&I) -> T,
+) -> Vec<Disagreement<I, T>> {
+    inputs
+        .into_iter()
+        .filter_map(|input| {
+            let (fast, naive) = (fast(&input), naive(&input));
+            (fast != naive).then_some(Disagreement { input, fast, naive })
+        })
+        .collect()
+}
+
+/// [`compare`] for tests, panicking with every disagreement at once.
+#[track_caller]
+pub fn assert_agree<I: Debug, T: PartialEq + Debug>(
+    inputs: impl IntoIterator<Item = I>,
+    fast: impl FnMut(&I) -> T,
+    naive: impl FnMut(&I) -> T,
+) {
+    let disagreements = compare(inputs, fast, naive);
+    if !disagreements.is_empty() {
+        panic!("{} disagreements:\n{}", disagreements.len(), disagreements.iter().join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle(&n: &u64) -> u64 {
+        (1..=n).sum()
+    }
+
+    #[test]
+    fn disagreements() {
+        assert_agree(0..100, |&n| n * (n + 1) / 2, triangle);
+
+        let disagreements = compare(0..100, |&n| n * n / 2, triangle);
+        assert_eq!(disagreements.len(), 99);
+        assert_eq!(disagreements[0], Disagreement { input: 1, fast: 0, naive: 1 });
+        assert_eq!(disagreements[0].to_string(), "fast gave 0 but naive gave 1 on 1");
+    }
+}
@@ -0,0 +1,128 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// Error raised when a puzzle input is malformed.
+///
+/// Parsers only need to point at the offending slice of their input; the line, column and
+/// source line are recovered afterwards by [`ParseError::locate`], once the whole input is known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub text: String,
+    /// 1-based, 0 until the error has been located.
+    pub line: usize,
+    /// 1-based, 0 until the error has been located.
+    pub column: usize,
+    source_line: String,
+    address: usize,
+}
+
+impl ParseError {
+    /// `text` must be a slice of the parsed input for the error to be located later on.
+    pub fn new(message: impl Into<String>, text: &str) -> Self {
+        Self {
+            message: message.into(),
+            text: text.to_string(),
+            line: 0,
+            column: 0,
+            source_line: String::new(),
+            address: text.as_ptr() as usize,
+        }
+    }
+
+    /// Computes the line and column of the error if its text is a slice of `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.line != 0 || self.address < start || self.address > start + input.len() {
+            return self;
+        }
+
+        let offset = self.address - start;
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+
+        self.line = input[..offset].matches('\n').count() + 1;
+        self.column = input[line_start..offset].chars().count() + 1;
+        self.source_line = input[line_start..line_end].trim_end_matches('\r').to_string();
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            return write!(f, "{}: `{}`", self.message, self.text);
+        }
+
+        writeln!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        writeln!(f, "    {}", self.source_line)?;
+        write!(f, "    {}{}", " ".repeat(self.column - 1), "^".repeat(self.text.chars().count().max(1)))
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a number, pointing at it on failure.
+pub fn parse_number<T: std::str::FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse().map_err(|_| ParseError::new("invalid number", text))
+}
+
+/// Strips a prefix, pointing at the start of `text` on failure.
+pub fn expect_prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(format!("expected `{}`", prefix), text.get(..prefix.len()).unwrap_or(text)))
+}
+
+/// Splits on the first occurrence of `delimiter`, pointing at `text` on failure.
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter).ok_or_else(|| ParseError::new(format!("expected `{}`", delimiter), text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let input = "Time: 7 15\nDistance: 9 x 200";
+        let err = ParseError::new("invalid number", &input[input.len() - 5..input.len() - 4]);
+        assert_eq!(err.to_string(), "invalid number: `x`");
+
+        let err = err.locate(input);
+        assert_eq!((err.line, err.column), (2, 13));
+        assert_eq!(err.to_string(), "line 2, column 13: invalid number\n    Distance: 9 x 200\n                ^");
+    }
+
+    #[test]
+    fn caret() {
+        // Columns count characters, and the caret spans the whole text
+        let input = "µs: 12ms";
+        let err = parse_number::<u32>(&input[5..]).unwrap_err().locate(input);
+        assert_eq!(err.to_string(), "line 1, column 5: invalid number\n    µs: 12ms\n        ^^^^");
+
+        // Nothing to point at still gets a caret
+        let input = "a\n";
+        let err = ParseError::new("expected a line", &input[2..]).locate(input);
+        assert_eq!((err.line, err.column), (2, 1));
+        assert!(err.to_string().ends_with("\n    ^"));
+    }
+
+    #[test]
+    fn elsewhere() {
+        // Text that is not a slice of the input cannot be located
+        let text = String::from("x");
+        let err = ParseError::new("invalid number", &text).locate("x");
+        assert_eq!(err.line, 0);
+        assert_eq!(err.to_string(), "invalid number: `x`");
+    }
+
+    #[test]
+    fn helpers() {
+        assert_eq!(expect_prefix("Game 1", "Game ").unwrap(), "1");
+        assert_eq!(expect_prefix("Gam", "Game ").unwrap_err().text, "Gam");
+        assert_eq!(expect_prefix("Round 1", "Game ").unwrap_err().text, "Round");
+        assert_eq!(split_once("a: b", ": ").unwrap(), ("a", "b"));
+        assert_eq!(split_once("a b", ": ").unwrap_err().message, "expected `: `");
+        assert_eq!(parse_number::<u8>("256").unwrap_err().text, "256");
+    }
+}
@@ -0,0 +1,216 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::input::{input_path, inputs_dir};
+
+/// Environment variable holding the value of the `session` cookie of the website.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding [`BASE_URL`], e.g. to point at a local server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Transport used to talk to the website, so that it can be replaced in tests.
+pub trait Http {
+    /// Body of a successful `GET` of `url`, sent with the given session cookie.
+    fn get(&self, url: &str, session: &str) -> io::Result<String>;
+
+    /// Body of a successful `POST` of an url-encoded form to `url`.
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> io::Result<String>;
+}
+
+/// [`Http`] backed by `ureq`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ureq;
+
+const USER_AGENT: &str = concat!("advent/", env!("CARGO_PKG_VERSION"));
+
+fn http_error(err: ureq::Error) -> io::Error {
+    match err {
+        ureq::Error::Status(code, response) => io::Error::other(format!("HTTP {} {}", code, response.status_text())),
+        err => io::Error::other(err),
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> io::Result<String> {
+        ureq::get(url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(http_error)?
+            .into_string()
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> io::Result<String> {
+        ureq::post(url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(form)
+            .map_err(http_error)?
+            .into_string()
+    }
+}
+
+/// Downloads puzzle inputs into a cache directory, as `<year>/dayN.txt` files like those of the
+/// `inputs` directory of [`inputs_dir`], which is the cache unless another directory is given.
+///
+/// A cached input is never downloaded again. Empty files, as created by `new-day`, do not count.
+#[derive(Debug, Clone)]
+pub struct Fetcher<H = Ureq> {
+    http: H,
+    session: String,
+    base_url: String,
+    cache: Option<PathBuf>,
+}
+
+impl Fetcher {
+    pub fn new(session: impl Into<String>) -> Self {
+        Self {
+            http: Ureq,
+            session: session.into(),
+            base_url: BASE_URL.to_string(),
+            cache: None,
+        }
+    }
+
+    /// Reads the session from [`SESSION_VAR`], and the base URL from [`BASE_URL_VAR`] when set.
+    pub fn from_env() -> Result<Self, String> {
+        let session = env::var(SESSION_VAR).map_err(|_| format!("{} is not set", SESSION_VAR))?;
+        let fetcher = Self::new(session.trim());
+        Ok(match env::var(BASE_URL_VAR) {
+            Ok(url) => fetcher.base_url(url),
+            Err(_) => fetcher,
+        })
+    }
+}
+
+impl<H: Http> Fetcher<H> {
+    pub fn http<T: Http>(self, http: T) -> Fetcher<T> {
+        Fetcher { http, session: self.session, base_url: self.base_url, cache: self.cache }
+    }
+
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn cache(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache = Some(dir.into());
+        self
+    }
+
+    pub fn path(&self, year: u32, day: usize) -> PathBuf {
+        match &self.cache {
+            Some(dir) => input_path(&dir.join(year.to_string()), day),
+            None => input_path(&inputs_dir(year), day),
+        }
+    }
+
+    /// Path of the input of a day, downloading it first unless it is cached.
+    pub fn fetch(&self, year: u32, day: usize) -> io::Result<PathBuf> {
+        let path = self.path(year, day);
+        if is_cached(&path) {
+            return Ok(path);
+        }
+
+        let input = self.http.get(&format!("{}/{}/day/{}/input", self.base_url, year, day), &self.session)?;
+        fs::create_dir_all(path.parent().unwrap_or(Path::new(".")))?;
+        // Write aside first, so that an interrupted download is not mistaken for a cached input
+        let partial = path.with_extension("part");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, &path)?;
+        Ok(path)
+    }
+}
+
+fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("advent-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    struct Counting(Cell<usize>);
+
+    impl Http for Counting {
+        fn get(&self, _: &str, _: &str) -> io::Result<String> {
+            self.0.set(self.0.get() + 1);
+            Ok("1 2 3\n".to_string())
+        }
+
+        fn post(&self, _: &str, _: &str, _: &[(&str, &str)]) -> io::Result<String> {
+            Err(io::Error::other("unexpected POST"))
+        }
+    }
+
+    #[test]
+    fn downloads_once() {
+        let dir = cache_dir("once");
+        let fetcher = Fetcher::new("token").cache(&dir).http(Counting(Cell::new(0)));
+
+        let path = dir.join("2023").join("day4.txt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "").unwrap();
+        for _ in 0..2 {
+            assert_eq!(fetcher.fetch(2023, 4).unwrap(), path);
+        }
+        assert_eq!(fetcher.http.0.get(), 1);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn years_apart() {
+        let dir = cache_dir("years");
+        let fetcher = Fetcher::new("token").cache(&dir).http(Counting(Cell::new(0)));
+
+        assert_eq!(fetcher.fetch(2022, 17).unwrap(), dir.join("2022").join("day17.txt"));
+        assert_eq!(fetcher.fetch(2023, 17).unwrap(), dir.join("2023").join("day17.txt"));
+        assert_eq!(fetcher.http.0.get(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn local_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            for line in BufReader::new(&stream).lines() {
+                let line = line.unwrap();
+                if line.is_empty() {
+                    break;
+                }
+                request.push(line);
+            }
+
+            let body = "#.#\n";
+            write!(&stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body).unwrap();
+            request
+        });
+
+        let dir = cache_dir("server");
+        let path = Fetcher::new("token").cache(&dir).base_url(base_url).fetch(2022, 17).unwrap();
+        assert_eq!(fs::read_to_string(path).unwrap(), "#.#\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2022/day/17/input HTTP/1.1");
+        assert!(request.iter().any(|line| line.eq_ignore_ascii_case("cookie: session=token")));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,170 @@
+use std::str::FromStr;
+
+use crate::answers::{Check, Status};
+use crate::{Answer, ParseError};
+
+/// Presentation of the results of a run, selected with `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(ParseError::new("expected one of `json`, `csv`, `table`", value)),
+        }
+    }
+}
+
+impl Format {
+    pub fn render(&self, checks: &[Check]) -> String {
+        match self {
+            Format::Table => table(checks),
+            Format::Json => json(checks),
+            Format::Csv => csv(checks),
+        }
+    }
+}
+
+/// Renders the checks as a table, followed by a summary line.
+pub fn table(checks: &[Check]) -> String {
+    let show = |answer: &Option<Answer>| answer.as_ref().map_or("-".to_string(), |answer| answer.to_string());
+
+    let mut res = format!(
+        "{:>4}  {:>4}  {:<9}  {:>20}  {:>20}  {:>10}  {:>10}\n",
+        "Day", "Part", "Status", "Answer", "Expected", "Parse", "Solve",
+    );
+    for check in checks {
+        res += &format!(
+            "{:>4}  {:>4}  {:<9}  {:>20}  {:>20}  {:>10.2?}  {:>10}\n",
+            check.name(), check.part, check.status, show(&check.answer), show(&check.expected),
+            check.parse_time, check.time.map_or("-".to_string(), |time| format!("{:.2?}", time)),
+        );
+    }
+
+    let count = |status: Status| checks.iter().filter(|check| check.status == status).count();
+    res += &format!(
+        "\n{} passed, {} mismatched, {} failed, {} skipped, {} unchecked\n",
+        count(Status::Pass), count(Status::Mismatch), count(Status::Fail), count(Status::Skipped), count(Status::Unchecked),
+    );
+    res
+}
+
+pub(crate) fn json_string(text: &str) -> String {
+    let mut res = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => res += "\\\"",
+            '\\' => res += "\\\\",
+            '\n' => res += "\\n",
+            c if c.is_control() => res += &format!("\\u{:04x}", c as u32),
+            c => res.push(c),
+        }
+    }
+    res + "\""
+}
+
+/// Numbers stay numbers, skipped or missing answers are `null`.
+fn json_answer(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(Answer::Number(n)) => n.to_string(),
+        Some(Answer::Text(text)) => json_string(text),
+        Some(Answer::Skipped(_)) | None => "null".to_string(),
+    }
+}
+
+/// Renders the checks as a JSON array with one object per part, durations in nanoseconds.
+pub fn json(checks: &[Check]) -> String {
+    let rows = checks
+        .iter()
+        .map(|check| {
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"variant\": {}, \"part\": {}, \"answer\": {}, \"expected\": {}, \"parse_ns\": {}, \"solve_ns\": {}, \"status\": {}}}",
+                check.year,
+                check.day,
+                check.variant.map_or("null".to_string(), json_string),
+                check.part,
+                json_answer(&check.answer),
+                json_answer(&check.expected),
+                check.parse_time.as_nanos(),
+                check.time.map_or("null".to_string(), |time| time.as_nanos().to_string()),
+                json_string(check.status.name()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if rows.is_empty() {
+        return "[]\n".to_string();
+    }
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+fn csv_field(answer: &Option<Answer>) -> String {
+    let text = match answer {
+        Some(Answer::Skipped(_)) | None => return String::new(),
+        Some(answer) => answer.to_string(),
+    };
+
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+/// Renders the checks as CSV with a header line, durations in nanoseconds.
+pub fn csv(checks: &[Check]) -> String {
+    let mut res = String::from("year,day,variant,part,answer,expected,parse_ns,solve_ns,status\n");
+    for check in checks {
+        res += &format!(
+            "{},{},{},{},{},{},{},{},{}\n",
+            check.year,
+            check.day,
+            check.variant.unwrap_or(""),
+            check.part,
+            csv_field(&check.answer),
+            csv_field(&check.expected),
+            check.parse_time.as_nanos(),
+            check.time.map_or(String::new(), |time| time.as_nanos().to_string()),
+            check.status.name(),
+        );
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_escaping() {
+        assert_eq!(json_string("plain"), r#""plain""#);
+        assert_eq!(json_string(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(json_string(r"C:\dir"), r#""C:\\dir""#);
+        assert_eq!(json_string("a\nb\tc\r\u{1}"), r#""a\nb\u0009c\u000d\u0001""#);
+        assert_eq!(json_string("Noël, ∑"), "\"Noël, ∑\"");
+        assert_eq!(json_string(""), r#""""#);
+    }
+
+    #[test]
+    fn csv_escaping() {
+        let field = |text: &str| csv_field(&Some(text.to_string().into()));
+        assert_eq!(field("plain"), "plain");
+        assert_eq!(field("a,b"), r#""a,b""#);
+        assert_eq!(field(r#"say "hi""#), r#""say ""hi""""#);
+        assert_eq!(field("two\nlines"), "\"two\nlines\"");
+        assert_eq!(field("cr\r"), "\"cr\r\"");
+        assert_eq!(field(r"C:\dir"), r"C:\dir");
+        assert_eq!(csv_field(&Some(Answer::Number(-42))), "-42");
+        assert_eq!(csv_field(&None), "");
+    }
+}
@@ -0,0 +1,455 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Random number generator giving the same inputs for the same seed, so that a failing input can be
+/// generated again.
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// Day 2: `games` games of 1 to 6 draws, each showing up to 20 cubes of some of the colors.
+pub fn games(rng: &mut impl Rng, games: usize) -> String {
+    (1..=games)
+        .map(|id| {
+            let draws = (0..rng.gen_range(1..=6))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    colors.shuffle(rng);
+                    let count = rng.gen_range(1..=3);
+                    colors[..count].iter().map(|color| format!("{} {}", rng.gen_range(1..=20), color)).join(", ")
+                })
+                .join("; ");
+            format!("Game {}: {}", id, draws)
+        })
+        .join("\n")
+}
+
+/// Day 4: `cards` cards of `winning` winning numbers and `numbers` numbers, all below 100. Cards never
+/// win copies of cards past the end of the table, nor so many that the total would not fit in an
+/// `i64`, as the copies can double with each card.
+pub fn cards(rng: &mut impl Rng, cards: usize, winning: usize, numbers: usize) -> String {
+    assert!(winning + numbers <= 99, "not enough numbers below 100");
+
+    // Copies of each card, final once its turn comes as only the cards before it win copies
+    let mut copies = vec![1u64; cards];
+    let mut total = cards as u64;
+    (1..=cards)
+        .map(|id| {
+            let mut pool = (1..100).collect_vec();
+            pool.shuffle(rng);
+            let amount = copies[id - 1];
+            let affordable = ((i64::MAX as u64 - total) / amount) as usize;
+            let matching = rng.gen_range(0..=winning.min(numbers).min(cards - id).min(affordable));
+            for next in &mut copies[id..id + matching] {
+                *next += amount;
+            }
+            total += amount * matching as u64;
+
+            // The first `matching` winning numbers are the ones we have, the others come after them
+            let (winning_numbers, rest) = pool.split_at(winning);
+            let mut our_numbers = winning_numbers[..matching].iter().chain(&rest[..numbers - matching]).collect_vec();
+            our_numbers.shuffle(rng);
+
+            format!(
+                "Card {:>3}: {} | {}",
+                id,
+                winning_numbers.iter().map(|n| format!("{:>2}", n)).join(" "),
+                our_numbers.iter().map(|n| format!("{:>2}", n)).join(" "),
+            )
+        })
+        .join("\n")
+}
+
+/// Day 6: `races` races lasting up to `max_time`, with records that can be beaten, even once the
+/// races are put together. Put together, times stay short enough for day 6 to square them in an
+/// `i64`.
+pub fn races(rng: &mut impl Rng, races: usize, max_time: u64) -> String {
+    assert!(max_time >= 2, "races need time to beat a record");
+    let longest = max_time.to_string().repeat(races).parse::<u128>().ok();
+    assert!(
+        longest.and_then(|time| time.checked_mul(time)).is_some_and(|square| square <= i64::MAX as u128),
+        "races put together too long to square in an i64"
+    );
+
+    // Holding the button for half the race goes the furthest
+    let furthest = |time: u128| (time / 2) * (time - time / 2);
+    loop {
+        let times = (0..races).map(|_| rng.gen_range(2..=max_time)).collect_vec();
+        let records = times.iter().map(|&time| rng.gen_range(0..furthest(time.into()))).collect_vec();
+
+        let time = times.iter().join("").parse::<u128>().unwrap();
+        let record = records.iter().join("").parse::<u128>().unwrap();
+        if record < furthest(time) {
+            // Neighbors must not run into each other, whichever of the times or records are longer
+            let numbers = times.iter().map(u64::to_string).chain(records.iter().map(u128::to_string));
+            let width = numbers.map(|n| n.len()).max().unwrap() + 2;
+            return format!(
+                "Time:     {}\nDistance: {}",
+                times.iter().map(|time| format!("{:>width$}", time)).join(""),
+                records.iter().map(|record| format!("{:>width$}", record)).join(""),
+            );
+        }
+    }
+}
+
+/// Day 7: `hands` hands of 5 cards, with bids up to 1000.
+pub fn hands(rng: &mut impl Rng, hands: usize) -> String {
+    let cards = "23456789TJQKA".chars().collect_vec();
+    (0..hands)
+        .map(|_| {
+            // Few distinct cards per hand, otherwise nearly all of them are high cards
+            let count = rng.gen_range(1..=5);
+            let kinds = cards.choose_multiple(rng, count).copied().collect_vec();
+            let hand = (0..5).map(|_| *kinds.choose(rng).unwrap()).collect::<String>();
+            format!("{} {}", hand, rng.gen_range(1..=1000))
+        })
+        .join("\n")
+}
+
+/// Digit of the names of day 8, among the letters that neither start nor end the paths.
+fn letter(n: usize) -> char {
+    (b'B' + (n % 24) as u8) as char
+}
+
+/// Day 8: `ghosts` ghosts each going from its start to its end in `instructions` times up to
+/// `max_cycle` steps, then looping back the same way, the first ghost going from `AAA` to `ZZZ`.
+pub fn network(rng: &mut impl Rng, ghosts: usize, instructions: usize, max_cycle: usize) -> String {
+    wandering_network(rng, ghosts, instructions, max_cycle, 0, 1)
+}
+
+/// Day 8 beyond what the puzzle promises: like [`network`], but each ghost first walks up to
+/// `max_offset` more steps before entering its loop, and the loop goes through up to `max_ends`
+/// ends. Then the ghosts need not all stand on an end after the least common multiple of the steps
+/// to their first one, nor ever.
+pub fn wandering_network(
+    rng: &mut impl Rng,
+    ghosts: usize,
+    instructions: usize,
+    max_cycle: usize,
+    max_offset: usize,
+    max_ends: usize,
+) -> String {
+    assert!(ghosts >= 1 && instructions >= 1 && max_ends >= 1, "networks need a ghost, instructions and an end");
+
+    let instructions = (0..instructions).map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' }).collect_vec();
+    let mut nodes = vec![];
+    let mut middle = 0;
+    let mut extra_ends = ghosts;
+    for ghost in 0..ghosts {
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            (format!("{}{}A", letter(ghost / 24), letter(ghost)), format!("{}{}Z", letter(ghost / 24), letter(ghost)))
+        };
+
+        // The loop is `path[offset + 1..]`, its length a multiple of the instructions so that they
+        // lead the same way on each turn, and the ghost's own end closes it
+        let offset = rng.gen_range(0..=max_offset);
+        let length = instructions.len() * rng.gen_range(1..=max_cycle);
+        let mut path = vec![start];
+        for _ in 1..offset + length {
+            path.push(format!("{}{}{}", letter(middle / 576), letter(middle / 24), letter(middle)));
+            middle += 1;
+        }
+        path.push(end);
+
+        let ends = rng.gen_range(1..=max_ends.min(length));
+        for step in rand::seq::index::sample(rng, length - 1, ends - 1) {
+            path[offset + 1 + step] = format!("{}{}Z", letter(extra_ends / 24), letter(extra_ends));
+            extra_ends += 1;
+        }
+
+        // The way out of the end is the one into the loop, the other way anywhere on the path
+        for (step, node) in path.iter().enumerate() {
+            let next = path[if step == offset + length { offset + 1 } else { step + 1 }].clone();
+            let other = path.choose(rng).unwrap().clone();
+            let (left, right) = if instructions[step % instructions.len()] == 'L' { (next, other) } else { (other, next) };
+            nodes.push(format!("{} = ({}, {})", node, left, right));
+        }
+    }
+
+    nodes.shuffle(rng);
+    format!("{}\n\n{}", instructions.iter().collect::<String>(), nodes.join("\n"))
+}
+
+/// Day 9: `sequences` sequences of `length` values of polynomials, of degree low enough for the
+/// differences to reach zero.
+pub fn sequences(rng: &mut impl Rng, sequences: usize, length: usize) -> String {
+    assert!(length >= 2, "sequences need two values to be extrapolated");
+
+    (0..sequences)
+        .map(|_| {
+            // First value of each row of differences, the last row being constant
+            let degree = rng.gen_range(0..length - 1).min(5);
+            let mut differences = (0..=degree).map(|_| rng.gen_range(-10i64..=10)).collect_vec();
+            (0..length)
+                .map(|_| {
+                    let value = differences[0];
+                    for i in 0..degree {
+                        differences[i] += differences[i + 1];
+                    }
+                    value
+                })
+                .join(" ")
+        })
+        .join("\n")
+}
+
+/// Day 11: a `width` by `height` image where a cell holds a galaxy with probability `density`.
+pub fn image(rng: &mut impl Rng, width: usize, height: usize, density: f64) -> String {
+    (0..height)
+        .map(|_| (0..width).map(|_| if rng.gen_bool(density) { '#' } else { '.' }).collect::<String>())
+        .join("\n")
+}
+
+/// Day 12: `rows` rows of `length` springs with at least one damaged, some of them unknown.
+pub fn spring_rows(rng: &mut impl Rng, rows: usize, length: usize) -> String {
+    assert!(length >= 1, "rows need a damaged spring");
+
+    (0..rows)
+        .map(|_| {
+            let mut springs = (0..length).map(|_| if rng.gen_bool(0.4) { '#' } else { '.' }).collect_vec();
+            springs[rng.gen_range(0..length)] = '#';
+
+            let groups = springs.iter().collect::<String>().split('.').filter(|group| !group.is_empty()).map(str::len).join(",");
+            for spring in &mut springs {
+                if rng.gen_bool(0.5) {
+                    *spring = '?';
+                }
+            }
+            format!("{} {}", springs.iter().collect::<String>(), groups)
+        })
+        .join("\n")
+}
+
+/// Day 15: `steps` steps on the lenses of `labels` distinct labels, so that they get replaced and
+/// removed.
+pub fn steps(rng: &mut impl Rng, steps: usize, labels: usize) -> String {
+    let labels = (0..labels)
+        .map(|_| (0..rng.gen_range(1..=6)).map(|_| rng.gen_range(b'a'..=b'z') as char).collect::<String>())
+        .collect_vec();
+    (0..steps)
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            if rng.gen_bool(0.3) {
+                format!("{}-", label)
+            } else {
+                format!("{}={}", label, rng.gen_range(1..=9))
+            }
+        })
+        .join(",")
+}
+
+/// Edges of a loop around `columns` columns of random heights from the bottom left corner, clockwise
+/// or not.
+fn histogram(rng: &mut impl Rng, columns: usize, max: usize) -> Vec<(char, usize)> {
+    assert!(max >= 2, "neighbor columns need different heights");
+    let widths = (0..columns).map(|_| rng.gen_range(1..=max)).collect_vec();
+    let mut heights: Vec<usize> = vec![];
+    while heights.len() < columns {
+        let height = rng.gen_range(1..=max);
+        // Two columns of the same height would need an empty edge between them
+        if heights.last() != Some(&height) {
+            heights.push(height);
+        }
+    }
+
+    let mut edges = vec![('U', heights[0])];
+    for i in 0..columns {
+        if i > 0 {
+            edges.push(if heights[i] > heights[i - 1] { ('U', heights[i] - heights[i - 1]) } else { ('D', heights[i - 1] - heights[i]) });
+        }
+        edges.push(('R', widths[i]));
+    }
+    edges.push(('D', heights[columns - 1]));
+    edges.push(('L', widths.iter().sum()));
+
+    // Back the other way, turning each edge around
+    if rng.gen_bool(0.5) {
+        edges.reverse();
+        for (direction, _) in &mut edges {
+            *direction = match direction {
+                'U' => 'D',
+                'D' => 'U',
+                'L' => 'R',
+                _ => 'L',
+            };
+        }
+    }
+    edges
+}
+
+/// Day 18: a dig plan going around `columns` columns, for both the directions and the colors.
+pub fn dig_plan(rng: &mut impl Rng, columns: usize) -> String {
+    assert!(columns >= 1, "dig plans need a column");
+
+    let plan = histogram(rng, columns, 10);
+    // The widths add up on the way back, and colors only have five hexadecimal digits
+    let colors = histogram(rng, columns, 0xfffff / columns);
+    plan.into_iter()
+        .zip(colors)
+        .map(|((direction, distance), (color_direction, color_distance))| {
+            let color_direction = "RDLU".find(color_direction).unwrap();
+            format!("{} {} (#{:05x}{})", direction, distance, color_distance, color_direction)
+        })
+        .join("\n")
+}
+
+fn workflow_name(index: usize) -> String {
+    if index == 0 {
+        "in".to_string()
+    } else {
+        format!("w{}", index)
+    }
+}
+
+/// Workflow after `index`, or a verdict.
+fn workflow_target(rng: &mut impl Rng, index: usize, workflows: usize) -> String {
+    match rng.gen_range(index + 1..workflows + 2) {
+        next if next < workflows => workflow_name(next),
+        next if next == workflows => "A".to_string(),
+        _ => "R".to_string(),
+    }
+}
+
+/// Day 19: `workflows` workflows starting from `in`, each sending parts further down the list so
+/// that they never loop, then `parts` parts.
+pub fn workflows(rng: &mut impl Rng, workflows: usize, parts: usize) -> String {
+    assert!(workflows >= 1, "parts start in the `in` workflow");
+
+    let mut lines = vec![];
+    for index in 0..workflows {
+        let rules = (0..rng.gen_range(1..=4))
+            .map(|_| {
+                let category = *['x', 'm', 'a', 's'].choose(rng).unwrap();
+                let comparison = if rng.gen_bool(0.5) { '<' } else { '>' };
+                let value = rng.gen_range(2..=4000);
+                format!("{}{}{}:{}", category, comparison, value, workflow_target(rng, index, workflows))
+            })
+            .collect_vec();
+        lines.push(format!("{}{{{},{}}}", workflow_name(index), rules.join(","), workflow_target(rng, index, workflows)));
+    }
+
+    lines.push(String::new());
+    for _ in 0..parts {
+        let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+        lines.push(format!("{{x={},m={},a={},s={}}}", x, m, a, s));
+    }
+    lines.join("\n")
+}
+
+/// Day 21: a square garden of side `2 * half + 1` shaped like the puzzle inputs: the start in the
+/// middle, nothing in the way along its row, its column and the edges, and `density` of rocks elsewhere.
+pub fn garden(rng: &mut impl Rng, half: usize, density: f64) -> String {
+    let size = 2 * half + 1;
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| match (x, y) {
+                    _ if x == half && y == half => 'S',
+                    _ if x == half || y == half || x == 0 || y == 0 || x == size - 1 || y == size - 1 => '.',
+                    _ if rng.gen_bool(density) => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+/// Day 22: `bricks` bricks of up to 4 cubes in a `size` by `size` column, none overlapping.
+pub fn bricks(rng: &mut impl Rng, bricks: usize, size: usize) -> String {
+    assert!(size >= 1, "bricks need room");
+
+    let mut occupied = HashSet::new();
+    let mut lines = vec![];
+    let mut top = 1;
+    while lines.len() < bricks {
+        let start = (rng.gen_range(0..size), rng.gen_range(0..size), rng.gen_range(1..=top));
+        let length = rng.gen_range(0..4);
+        let end = match rng.gen_range(0..3) {
+            0 => (start.0 + length, start.1, start.2),
+            1 => (start.0, start.1 + length, start.2),
+            _ => (start.0, start.1, start.2 + length),
+        };
+        if end.0 >= size || end.1 >= size {
+            continue;
+        }
+
+        let cubes = (start.0..=end.0)
+            .cartesian_product(start.1..=end.1)
+            .cartesian_product(start.2..=end.2)
+            .map(|((x, y), z)| (x, y, z))
+            .collect_vec();
+        if cubes.iter().any(|cube| occupied.contains(cube)) {
+            continue;
+        }
+
+        occupied.extend(cubes);
+        top = top.max(end.2 + 1);
+        lines.push(format!("{},{},{}~{},{},{}", start.0, start.1, start.2, end.0, end.1, end.2));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2023::*;
+    use crate::Solver;
+
+    /// Runs both parts of a day on inputs of growing sizes, which must parse and not panic. The
+    /// answers themselves are left to each day's `matches_naive`.
+    fn smoke<S: Solver>(input: impl Fn(&mut StdRng, usize) -> String) {
+        for seed in 0..20 {
+            let input = input(&mut rng(seed), 1 + seed as usize);
+            let mut solution = S::from_input(&input).unwrap_or_else(|err| panic!("{}\n{}", err, input));
+            solution.part1();
+            solution.part2();
+        }
+    }
+
+    #[test]
+    fn reproducible() {
+        assert_eq!(games(&mut rng(7), 10), games(&mut rng(7), 10));
+        assert_ne!(games(&mut rng(7), 10), games(&mut rng(8), 10));
+    }
+
+    #[test]
+    fn days() {
+        smoke::<day2::Solution>(games);
+        smoke::<day4::Solution>(|rng, size| cards(rng, size * 10, 10, 25));
+        smoke::<day6::Solution>(|rng, size| races(rng, size.min(4), 99));
+        smoke::<day7::Solution>(hands);
+        smoke::<day8::Solution>(|rng, size| network(rng, size.min(6), size, 5));
+        smoke::<day9::Solution>(|rng, size| sequences(rng, size, size + 1));
+        smoke::<day11::Solution>(|rng, size| image(rng, size + 1, size + 2, 0.2));
+        smoke::<day12::Solution>(|rng, size| spring_rows(rng, size, size));
+        smoke::<day15::Solution>(|rng, size| steps(rng, size * 10, size));
+        smoke::<day18::Solution>(dig_plan);
+        smoke::<day19::Solution>(|rng, size| workflows(rng, size, size * 10));
+        smoke::<day21::Solution>(|rng, size| garden(rng, size + 2, 0.1));
+        smoke::<day22::Solution>(|rng, size| bricks(rng, size * 5, 3));
+    }
+
+    #[test]
+    fn rectangles() {
+        // A single column is a rectangle, its area counts the trench as well
+        for seed in 0..10 {
+            let plan = dig_plan(&mut rng(seed), 1);
+            let distances = plan.lines().map(|line| line.split(' ').nth(1).unwrap().parse::<i64>().unwrap()).collect_vec();
+            let expected = (distances[0] + 1) * (distances[1] + 1);
+            assert_eq!(day18::Solution::from_input(&plan).unwrap().part1(), expected.into());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "races put together too long to square in an i64")]
+    fn races_too_long() {
+        // 100100100100 squared is past an i64, unlike 99999999 squared in `days`
+        races(&mut rng(0), 4, 100);
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::Point;
+use crate::ParseError;
+
+/// Rectangular map of cells, indexed by `(x, y)` positions or [`Point`]s, with `y` growing downwards.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self where T: Clone {
+        Self { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Builds a grid from text, one row per line, `cell` mapping each character to a cell or
+    /// rejecting it with `None`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let mut count = 0;
+            for (index, c) in line.char_indices() {
+                let text = &line[index..index + c.len_utf8()];
+                cells.push(cell(c).ok_or_else(|| ParseError::new("unexpected character", text))?);
+                count += 1;
+            }
+
+            match width {
+                None => width = Some(count),
+                Some(width) if width != count => {
+                    return Err(ParseError::new(format!("expected a row of {} cells", width), line));
+                },
+                Some(_) => {},
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self { width, height, cells }),
+            _ => Err(ParseError::new("expected at least one cell", input)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.y >= 0 && (point.x as usize) < self.width && (point.y as usize) < self.height
+    }
+
+    /// `None` outside of the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point).then(|| &self.cells[point.y as usize * self.width + point.x as usize])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.y as usize * self.width + point.x as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Cell of the grid repeated infinitely in every direction.
+    pub fn get_wrapping(&self, point: Point) -> &T {
+        let x = point.x.rem_euclid(self.width as isize) as usize;
+        let y = point.y.rem_euclid(self.height as isize) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    fn neighbors<'a>(&self, (x, y): (usize, usize), deltas: &'a [(isize, isize)]) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let (width, height) = (self.width, self.height);
+        deltas.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx).filter(|&x| x < width)?;
+            let y = y.checked_add_signed(dy).filter(|&y| y < height)?;
+            Some((x, y))
+        })
+    }
+
+    /// Positions right, below, left and above `position`, within the grid.
+    pub fn neighbors4(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors(position, &[(1, 0), (0, 1), (-1, 0), (0, -1)])
+    }
+
+    /// Positions around `position` including diagonals, within the grid.
+    pub fn neighbors8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors(position, &[(1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1), (0, -1), (1, -1)])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(index, cell)| ((index % width, index / width), cell))
+    }
+
+    /// Positions of the cells matching `predicate`, row by row.
+    pub fn positions<'a>(&'a self, predicate: impl Fn(&T) -> bool + 'a) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter().filter(move |(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    /// First position of a cell matching `predicate`, row by row.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.positions(predicate).next()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width).flat_map(|x| self.column(x).cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells = (0..self.width).flat_map(|x| self.column(x).rev().cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let cells = (0..self.width).rev().flat_map(|x| self.column(x).cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside of the grid", x, y);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside of the grid", x, y);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| panic!("{} is outside of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point).unwrap_or_else(|| panic!("{} is outside of the grid", point))
+    }
+}
+
+/// One line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+ab
+cd
+ef";
+
+    fn example() -> Grid<char> {
+        Grid::parse(EXAMPLE, Some).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.to_string(), format!("{}\n", EXAMPLE));
+
+        let input = "ab\nc";
+        let err = Grid::parse(input, Some).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (2, 1));
+        let input = "ab\na!";
+        let err = Grid::parse(input, |c| c.is_alphabetic().then_some(c)).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn get() {
+        let grid = example();
+        assert_eq!(grid.get(Point::new(1, 0)), Some(&'b'));
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.get_wrapping(Point::new(-1, 3)), &'b');
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = example();
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+        assert_eq!(grid.column(1).collect::<String>(), "bdf");
+        assert_eq!(grid.positions(|&c| c > 'c').collect::<Vec<_>>(), [(1, 1), (0, 2), (1, 2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 2 is outside of the grid")]
+    fn column_outside() {
+        // Would be column 0 from the second row on
+        let _ = example().column(2);
+    }
+
+    #[test]
+    fn rotations() {
+        let grid = example();
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "bdf\nace\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+}
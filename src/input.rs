@@ -0,0 +1,86 @@
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::parse_number;
+use crate::ParseError;
+
+/// Directory holding the `dayN.txt` puzzle inputs of a year, independent of the working directory.
+pub fn inputs_dir(year: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs").join(year.to_string())
+}
+
+pub fn input_path(dir: &Path, day: usize) -> PathBuf {
+    dir.join(format!("day{}.txt", day))
+}
+
+/// Reads a puzzle input from a file, or from stdin when `path` is `-`.
+pub fn read_input(path: impl AsRef<Path>) -> io::Result<String> {
+    if path.as_ref() == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        read_to_string(path)
+    }
+}
+
+/// Puzzle input without what editors and browsers may add to it: a byte order mark, `\r\n` line
+/// endings, spaces at the end of lines and blank lines at the end. Every solution parses its input
+/// through it, see [`Solver::from_input`](crate::Solver::from_input).
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut res = String::with_capacity(input.len());
+    for line in input.lines() {
+        res.push_str(line.trim_end());
+        res.push('\n');
+    }
+    res.truncate(res.trim_end().len());
+    res
+}
+
+/// Paragraphs of the input, separated by one or more blank lines.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input.split("\n\n").map(|block| block.trim_matches('\n')).filter(|block| !block.is_empty())
+}
+
+/// Lines of the input, skipping blank ones.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().filter(|line| !line.trim().is_empty())
+}
+
+/// Parts of `text` between each `separator`, without the spaces around them.
+pub fn fields(text: &str, separator: char) -> impl Iterator<Item = &str> {
+    text.split(separator).map(str::trim)
+}
+
+/// Numbers separated by any amount of whitespace, pointing at the first invalid one.
+pub fn numbers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace().map(parse_number).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_input() {
+        assert_eq!(normalize("\u{feff}a b \r\n\r\nc\t\r\n\r\n\n"), "a b\n\nc");
+        assert_eq!(normalize("a\nb\n"), "a\nb");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn helpers() {
+        let input = "a\nb\n\n\n\nc\n";
+        assert_eq!(blocks(input).collect::<Vec<_>>(), ["a\nb", "c"]);
+        assert_eq!(lines(input).collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(fields("3 blue,  4 red ; 1 green", ';').collect::<Vec<_>>(), ["3 blue,  4 red", "1 green"]);
+        assert_eq!(numbers::<i64>(" 7  15\t-30 ").unwrap(), [7, 15, -30]);
+
+        let input = "1 2 x3";
+        let err = numbers::<u32>(input).unwrap_err().locate(input);
+        assert_eq!((err.column, err.text.as_str()), (5, "x3"));
+    }
+}
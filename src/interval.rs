@@ -0,0 +1,251 @@
+use std::fmt::{self, Display};
+
+/// Integers from `start` included to `end` excluded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// Integers from `start` to `end`, both included.
+    pub const fn inclusive(start: i64, end: i64) -> Self {
+        Self::new(start, end + 1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn len(&self) -> u64 {
+        self.end.saturating_sub(self.start).max(0) as u64
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn intersection(&self, other: Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// Union of intervals, kept as sorted intervals that neither overlap nor touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.intervals.partition_point(|interval| interval.end <= value);
+        self.intervals.get(index).is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // Merge with every interval overlapping or touching it
+        let first = self.intervals.partition_point(|other| other.end < interval.start);
+        let last = self.intervals.partition_point(|other| other.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, other| Interval::new(merged.start.min(other.start), merged.end.max(other.end)));
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut res = self.clone();
+        for &interval in &other.intervals {
+            res.insert(interval);
+        }
+        res
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut res = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let common = a.intersection(*b);
+            if !common.is_empty() {
+                res.push(common);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals: res }
+    }
+
+    /// Integers of `self` that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut res = vec![];
+        let mut others = other.intervals.iter().peekable();
+        for &interval in &self.intervals {
+            let mut start = interval.start;
+            while let Some(other) = others.peek() {
+                if other.end <= start {
+                    others.next();
+                    continue;
+                }
+                if other.start >= interval.end {
+                    break;
+                }
+
+                if other.start > start {
+                    res.push(Interval::new(start, other.start));
+                }
+                start = other.end;
+                if other.end > interval.end {
+                    break;
+                }
+                others.next();
+            }
+            if start < interval.end {
+                res.push(Interval::new(start, interval.end));
+            }
+        }
+        IntervalSet { intervals: res }
+    }
+
+    /// Integers below `value`, and those from `value` on.
+    pub fn split_at(&self, value: i64) -> (IntervalSet, IntervalSet) {
+        let below = IntervalSet::from(Interval::new(i64::MIN, value));
+        (self.intersection(&below), self.difference(&below))
+    }
+
+    pub fn shift(&self, offset: i64) -> IntervalSet {
+        IntervalSet { intervals: self.intervals.iter().map(|interval| interval.shift(offset)).collect() }
+    }
+
+    /// Moves the integers in each `source` interval by its offset, leaving the others in place, as
+    /// if the sources did not overlap.
+    pub fn map(&self, shifts: impl IntoIterator<Item = (Interval, i64)>) -> IntervalSet {
+        let mut sources = IntervalSet::new();
+        let mut res = IntervalSet::new();
+        for (source, offset) in shifts {
+            let source = IntervalSet::from(source);
+            res = res.union(&self.intersection(&source).shift(offset));
+            sources = sources.union(&source);
+        }
+        res.union(&self.difference(&sources))
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        let mut res = IntervalSet::new();
+        res.insert(interval);
+        res
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut res = IntervalSet::new();
+        for interval in iter {
+            res.insert(interval);
+        }
+        res
+    }
+}
+
+impl Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (index, interval) in self.intervals.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", interval)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals.iter().map(|&(start, end)| Interval::new(start, end)).collect()
+    }
+
+    #[test]
+    fn insert() {
+        assert_eq!(set(&[(5, 8), (0, 2), (2, 3), (7, 10), (20, 20)]), set(&[(0, 3), (5, 10)]));
+        assert_eq!(set(&[(0, 3), (5, 10)]).len(), 8);
+        assert_eq!(set(&[(0, 3), (5, 10)]).to_string(), "{0..3, 5..10}");
+        assert!(set(&[(0, 3), (5, 10)]).contains(9));
+        assert!(!set(&[(0, 3), (5, 10)]).contains(3));
+    }
+
+    #[test]
+    fn operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+        assert_eq!(a.union(&b), set(&[(0, 40)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (28, 30)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 28)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20), (30, 40)]));
+        assert_eq!(a.difference(&set(&[(-5, 50)])), set(&[]));
+        assert_eq!(a.shift(-10), set(&[(-10, 0), (10, 20)]));
+    }
+
+    #[test]
+    fn split_at() {
+        let (below, above) = IntervalSet::from(Interval::inclusive(1, 4000)).split_at(2006);
+        assert_eq!(below, IntervalSet::from(Interval::inclusive(1, 2005)));
+        assert_eq!(above, IntervalSet::from(Interval::inclusive(2006, 4000)));
+    }
+
+    #[test]
+    fn map() {
+        let seeds = set(&[(79, 93)]);
+        assert_eq!(seeds.map([(Interval::new(98, 100), -48), (Interval::new(50, 98), 2)]), set(&[(81, 95)]));
+        assert_eq!(set(&[(0, 10)]).map([(Interval::new(5, 7), 100)]), set(&[(0, 5), (7, 10), (105, 107)]));
+    }
+}
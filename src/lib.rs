@@ -1,28 +1,67 @@
 #![allow(dead_code)]
 
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-pub mod day10;
-pub mod day10b;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod differential;
+pub mod error;
+pub mod fetch;
+pub mod format;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod render;
+pub mod report;
+pub mod scaffold;
+pub mod search;
+pub mod select;
+pub mod submit;
+pub mod trace;
+mod solver;
+pub use error::ParseError;
+pub use report::{PartReport, Report};
+pub use solver::{run, Answer, Day, DynSolver, Solver};
+
+pub mod y2023;
+
+/// Every solution of every year, in order.
+pub static DAYS: &[Day] = &[
+    Day::of::<y2023::day1::Solution>(),
+    Day::of::<y2023::day2::Solution>(),
+    Day::of::<y2023::day3::Solution>(),
+    Day::of::<y2023::day4::Solution>(),
+    Day::of::<y2023::day5::Solution>(),
+    Day::of::<y2023::day6::Solution>(),
+    Day::of::<y2023::day7::Solution>(),
+    Day::of::<y2023::day8::Solution>(),
+    Day::of::<y2023::day9::Solution>(),
+    Day::of::<y2023::day10::Solution>(),
+    Day::of::<y2023::day10b::Solution>(),
+    Day::of::<y2023::day11::Solution>(),
+    Day::of::<y2023::day12::Solution>(),
+    Day::of::<y2023::day13::Solution>(),
+    Day::of::<y2023::day14::Solution>(),
+    Day::of::<y2023::day15::Solution>(),
+    Day::of::<y2023::day16::Solution>(),
+    Day::of::<y2023::day17::Solution>(),
+    Day::of::<y2023::day18::Solution>(),
+    Day::of::<y2023::day19::Solution>(),
+    Day::of::<y2023::day20::Solution>(),
+    Day::of::<y2023::day21::Solution>(),
+    Day::of::<y2023::day22::Solution>(),
+    Day::of::<y2023::day23::Solution>(),
+    Day::of::<y2023::day24::Solution>(),
+    Day::of::<y2023::day25::Solution>(),
+];
+
+/// Looks up a day in [`DAYS`], `variant` selecting e.g. `day10b` over `day10`.
+pub fn find_day(year: u32, day: usize, variant: Option<&str>) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.year == year && entry.day == day && entry.variant == variant)
+}
+
+/// Most recent year of the calendar, the default when no year is given.
+pub fn latest_year() -> u32 {
+    DAYS.iter().map(|day| day.year).max().unwrap_or(0)
+}
\ No newline at end of file
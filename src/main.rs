@@ -1,33 +1,310 @@
-#![allow(dead_code)]
-#![allow(unused_imports)]
-
-use advent::day1;
-use advent::day2;
-use advent::day3;
-use advent::day4;
-use advent::day5;
-use advent::day6;
-use advent::day7;
-use advent::day8;
-use advent::day9;
-use advent::day10;
-use advent::day10b;
-use advent::day11;
-use advent::day12;
-use advent::day13;
-use advent::day14;
-use advent::day15;
-use advent::day16;
-use advent::day17;
-use advent::day18;
-use advent::day19;
-use advent::day20;
-use advent::day21;
-use advent::day22;
-use advent::day23;
-use advent::day24;
-use advent::day25;
+use std::env;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use advent::answers::{answers_path, Answers};
+use advent::format::Format;
+use advent::fetch::Fetcher;
+use advent::input::{input_path, inputs_dir, read_input};
+use advent::scaffold;
+use advent::select::DaySet;
+use advent::submit::{log_path, Guess, Log, Submitter, Verdict};
+use advent::trace::JsonLines;
+use advent::{find_day, latest_year, run, Answer, Day, DynSolver, DAYS};
+
+const USAGE: &str = "Usage: advent [<year>] <day> [--part <1|2>] [--variant <name>] [--input <file|->]
+                             [--check [--answers <file>]] [--format <table|json|csv>] [--render <file.svg|file.ppm>]
+                             [--trace <file.jsonl>]
+       advent [<year>] all [--part <1|2>] [--input <dir>] [--check [--answers <file>]] [--format <table|json|csv>]
+       advent [<year>] list
+       advent new-day <day> [--year <year>] [--title <title>]
+       advent fetch <days> [--year <year>] [--cache <dir>] [--base-url <url>]
+       advent submit [<year>] <day> <1|2> [--variant <name>] [--input <file|->] [--log <file>] [--base-url <url>]
+<year> defaults to the most recent year with solutions
+<days> is a list of days or inclusive ranges, e.g. `5,22` or `10..15`
+`--render` saves a picture of the state of the days that support it, after running them
+`--trace` writes the steps of the simulations of the days that support it, one JSON object per line
+`fetch` and `submit` read the session cookie from AOC_SESSION, and the base URL from AOC_BASE_URL if set";
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn list(year: Option<u32>) {
+    for day in DAYS.iter().filter(|day| year.is_none_or(|year| day.year == year)) {
+        println!("{}  {:>4}  {}", day.year, day.name(), day.title);
+    }
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn parse(day: &Day, input: &str) -> Box<dyn DynSolver> {
+    day.parse(input).unwrap_or_else(|err| fail(format!("Cannot parse input of day {}: {}", day.name(), err)))
+}
+
+fn load(day: &Day, path: &Path) -> Box<dyn DynSolver> {
+    match read_input(path) {
+        Ok(content) => parse(day, &content),
+        Err(err) => fail(format!("Cannot read input {}: {}", path.display(), err)),
+    }
+}
+
+fn load_answers(path: &Path) -> Answers {
+    let content = read_to_string(path)
+        .unwrap_or_else(|err| fail(format!("Cannot read answers {}: {}", path.display(), err)));
+    Answers::parse(&content).unwrap_or_else(|err| fail(format!("Cannot parse answers {}: {}", path.display(), err)))
+}
+
+/// Runs a single day, tracing it and rendering it afterwards when asked to.
+fn inspect_day(day: &Day, input: &Path, parts: &[usize], picture: Option<&Path>, trace: Option<&Path>) {
+    let mut solver = load(day, input);
+    if let Some(trace) = trace {
+        let Some(tracer) = solver.tracer() else {
+            fail(format!("Day {} has nothing to trace", day.name()));
+        };
+        let observer = JsonLines::create(trace)
+            .unwrap_or_else(|err| fail(format!("Cannot write trace {}: {}", trace.display(), err)));
+        tracer.attach(Box::new(observer));
+    }
+
+    print!("{}", run(solver.as_mut(), parts));
+
+    if let Some(trace) = trace {
+        let tracer = solver.tracer().unwrap();
+        tracer.finish().unwrap_or_else(|err| fail(format!("Cannot write trace {}: {}", trace.display(), err)));
+        println!("Wrote {}", trace.display());
+    }
+
+    if let Some(picture) = picture {
+        let Some(canvas) = solver.render() else {
+            fail(format!("Day {} has nothing to render", day.name()));
+        };
+        canvas
+            .save(picture)
+            .unwrap_or_else(|err| fail(format!("Cannot write picture {}: {}", picture.display(), err)));
+        println!("Wrote {}", picture.display());
+    }
+}
+
+fn new_day(mut args: impl Iterator<Item = String>) {
+    let mut day = None;
+    let mut year = latest_year();
+    let mut title = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-y" | "--year" => year = args.next().and_then(|year| year.parse().ok()).unwrap_or_else(|| usage()),
+            "-t" | "--title" => title = Some(args.next().unwrap_or_else(|| usage())),
+            _ if day.is_none() => day = Some(arg.parse::<usize>().ok().filter(|day| (1..=25).contains(day)).unwrap_or_else(|| usage())),
+            _ => usage(),
+        }
+    }
+    let day = day.unwrap_or_else(|| usage());
+    let title = title.unwrap_or_else(|| format!("Day {}", day));
+
+    match scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), year, day, &title) {
+        Ok(files) => {
+            for file in files {
+                println!("Wrote {}", file.display());
+            }
+        },
+        Err(err) => fail(format!("Cannot create day {}: {}", day, err)),
+    }
+}
+
+fn fetch(mut args: impl Iterator<Item = String>) {
+    let mut days = None;
+    let mut year = latest_year();
+    let mut fetcher = Fetcher::from_env().unwrap_or_else(|err| fail(format!("Cannot fetch inputs: {}", err)));
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-y" | "--year" => year = args.next().and_then(|year| year.parse().ok()).unwrap_or_else(|| usage()),
+            "--cache" => fetcher = fetcher.cache(args.next().unwrap_or_else(|| usage())),
+            "--base-url" => fetcher = fetcher.base_url(args.next().unwrap_or_else(|| usage())),
+            _ if days.is_none() => {
+                days = Some(arg.parse::<DaySet>().unwrap_or_else(|err| fail(format!("Invalid days `{}`: {}", arg, err))))
+            },
+            _ => usage(),
+        }
+    }
+    let days = days.unwrap_or_else(|| usage());
+
+    for day in (1..=25).filter(|&day| days.contains(day)) {
+        match fetcher.fetch(year, day) {
+            Ok(path) => println!("{}", path.display()),
+            Err(err) => fail(format!("Cannot fetch input of day {}: {}", day, err)),
+        }
+    }
+}
+
+fn submit(mut args: impl Iterator<Item = String>) {
+    let mut numbers = Vec::new();
+    let mut variant = None;
+    let mut input = None;
+    let mut log_file = log_path();
+    let mut submitter = Submitter::from_env().unwrap_or_else(|err| fail(format!("Cannot submit answers: {}", err)));
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-v" | "--variant" => variant = Some(args.next().unwrap_or_else(|| usage())),
+            "-i" | "--input" => input = Some(PathBuf::from(args.next().unwrap_or_else(|| usage()))),
+            "-l" | "--log" => log_file = PathBuf::from(args.next().unwrap_or_else(|| usage())),
+            "--base-url" => submitter = submitter.base_url(args.next().unwrap_or_else(|| usage())),
+            _ => numbers.push(arg.parse::<usize>().unwrap_or_else(|_| usage())),
+        }
+    }
+    let (year, number, part) = match numbers[..] {
+        [number, part] => (latest_year(), number, part),
+        [year, number, part] => (year as u32, number, part),
+        _ => usage(),
+    };
+    if part != 1 && part != 2 {
+        usage();
+    }
+
+    let Some(day) = find_day(year, number, variant.as_deref()) else {
+        fail(format!("No solution for day {}{} of {}", number, variant.as_deref().unwrap_or(""), year));
+    };
+    let answer = match load(day, &input.unwrap_or_else(|| input_path(&inputs_dir(year), day.day))).part(part) {
+        Answer::Skipped(reason) => fail(format!("Day {} part {} has no answer: {}", day.name(), part, reason)),
+        answer => answer.to_string(),
+    };
+    println!("Day {} part {}: {}", day.name(), part, answer);
+
+    let mut log = Log::load(&log_file).unwrap_or_else(|err| fail(format!("Cannot read log {}: {}", log_file.display(), err)));
+    if let Some(reason) = log.refusal(year, day.day, part, &answer) {
+        fail(format!("Not submitting {}: {}", answer, reason));
+    }
+
+    let verdict = submitter
+        .submit(year, day.day, part, &answer)
+        .unwrap_or_else(|err| fail(format!("Cannot submit answer: {}", err)));
+    println!("{}", verdict);
+
+    if verdict.is_final() {
+        let guess = Guess { year, day: day.day, part, answer, verdict: verdict.clone() };
+        log.record(&log_file, guess)
+            .unwrap_or_else(|err| fail(format!("Cannot write log {}: {}", log_file.display(), err)));
+    }
+    if verdict != Verdict::Right {
+        process::exit(1);
+    }
+}
 
 fn main() {
-    day25::Solution::init().solve();
-}
\ No newline at end of file
+    match env::args().nth(1).as_deref() {
+        Some("new-day") => return new_day(env::args().skip(2)),
+        Some("fetch") => return fetch(env::args().skip(2)),
+        Some("submit") => return submit(env::args().skip(2)),
+        _ => {},
+    }
+    let mut args = env::args().skip(1);
+
+    let mut year = None;
+    let mut target = None;
+    let mut part = None;
+    let mut variant = None;
+    let mut input = None;
+    let mut check = false;
+    let mut answers = None;
+    let mut format = None;
+    let mut render = None;
+    let mut trace = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--part" => {
+                part = match args.next().as_deref() {
+                    Some("1") => Some(1),
+                    Some("2") => Some(2),
+                    _ => usage(),
+                }
+            },
+            "-v" | "--variant" => variant = Some(args.next().unwrap_or_else(|| usage())),
+            "-i" | "--input" => input = Some(args.next().unwrap_or_else(|| usage())),
+            "-c" | "--check" => check = true,
+            "-a" | "--answers" => answers = Some(PathBuf::from(args.next().unwrap_or_else(|| usage()))),
+            "-f" | "--format" => {
+                let arg = args.next().unwrap_or_else(|| usage());
+                format = Some(arg.parse::<Format>().unwrap_or_else(|err| fail(format!("Invalid format `{}`: {}", arg, err))));
+            },
+            "-r" | "--render" => render = Some(PathBuf::from(args.next().unwrap_or_else(|| usage()))),
+            "-t" | "--trace" => trace = Some(PathBuf::from(args.next().unwrap_or_else(|| usage()))),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            },
+            // A second positional argument means the first one was the year
+            _ if year.is_none() && !arg.starts_with('-') => {
+                if let Some(first) = target.replace(arg) {
+                    year = Some(first.parse::<u32>().unwrap_or_else(|_| usage()));
+                }
+            },
+            _ => usage(),
+        }
+    }
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let listed = year;
+    let year = year.unwrap_or_else(latest_year);
+
+    // With `all`, `--input` is a directory of `dayN.txt` files, otherwise it is the input itself.
+    let (days, dir, file): (Vec<&Day>, _, _) = match target.as_deref() {
+        None | Some("list") => return list(listed),
+        Some("all") => {
+            let days = DAYS.iter().filter(|day| day.year == year).collect();
+            match input {
+                Some(dir) if dir != "-" => (days, PathBuf::from(dir), None),
+                Some(_) => usage(),
+                None => (days, inputs_dir(year), None),
+            }
+        },
+        Some(target) => {
+            let Ok(number) = target.parse::<usize>() else {
+                usage();
+            };
+
+            match find_day(year, number, variant.as_deref()) {
+                Some(day) => (vec![day], inputs_dir(year), input.map(PathBuf::from)),
+                None => fail(format!("No solution for day {}{} of {}", number, variant.as_deref().unwrap_or(""), year)),
+            }
+        },
+    };
+    let path = |day: &Day| file.clone().unwrap_or_else(|| input_path(&dir, day.day));
+
+    if render.is_some() || trace.is_some() {
+        // Only one day can be inspected at a time
+        if target.as_deref() == Some("all") || check || format.is_some() {
+            usage();
+        }
+        return inspect_day(days[0], &path(days[0]), &parts, render.as_deref(), trace.as_deref());
+    }
+
+    // Any explicit format reports the parts as rows, the classic output stays the default
+    if check || format.is_some() {
+        let answers = if check { load_answers(&answers.unwrap_or_else(answers_path)) } else { Answers::default() };
+        let checks = days
+            .iter()
+            .flat_map(|day| answers.check(day, read_input(path(day)), &parts))
+            .collect::<Vec<_>>();
+
+        print!("{}", format.unwrap_or_default().render(&checks));
+        if checks.iter().any(|check| check.status.is_failure()) {
+            process::exit(1);
+        }
+    } else {
+        for day in days {
+            print!("{}", run(load(day, &path(day)).as_mut(), &parts));
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+
+/// Side of a cell in the saved pictures, in pixels.
+pub const CELL_SIZE: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GRAY: Color = Color::rgb(128, 128, 128);
+    pub const DARK: Color = Color::rgb(32, 32, 48);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(80, 200, 80);
+    pub const BLUE: Color = Color::rgb(38, 139, 210);
+    pub const YELLOW: Color = Color::rgb(250, 210, 40);
+    pub const ORANGE: Color = Color::rgb(240, 120, 30);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Shade of gray from black for 0 to white for 1.
+    pub fn gray(level: f64) -> Self {
+        let level = (level.clamp(0., 1.) * 255.).round() as u8;
+        Self::rgb(level, level, level)
+    }
+}
+
+/// Picture of a grid, one colored square per cell, to debug solutions by looking at them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    cells: Grid<Color>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize, background: Color) -> Self {
+        Self { cells: Grid::new(width, height, background) }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl FnMut(&T) -> Color) -> Self {
+        Self { cells: grid.map(color) }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    /// Paints the given cells over the picture, ignoring those outside of it.
+    pub fn overlay(&mut self, points: impl IntoIterator<Item = Point>, color: Color) {
+        for point in points {
+            if let Some(cell) = self.cells.get_mut(point) {
+                *cell = color;
+            }
+        }
+    }
+
+    /// Binary PPM (`P6`) picture, each cell taking `scale` by `scale` pixels.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let mut res = format!("P6\n{} {}\n255\n", self.width() * scale, self.height() * scale).into_bytes();
+        for row in self.cells.rows() {
+            for _ in 0..scale {
+                for color in row {
+                    for _ in 0..scale {
+                        res.extend([color.r, color.g, color.b]);
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    /// SVG picture, each cell taking `scale` by `scale` pixels. Runs of cells of the same color
+    /// share a rectangle to keep the file small.
+    pub fn to_svg(&self, scale: usize) -> String {
+        let mut res = String::new();
+        let (width, height) = (self.width(), self.height());
+        writeln!(
+            res,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" shape-rendering="crispEdges">"#,
+            width * scale, height * scale, width, height,
+        ).unwrap();
+
+        for (y, row) in self.cells.rows().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let color = row[x];
+                let run = row[x..].iter().take_while(|&&other| other == color).count();
+                writeln!(
+                    res,
+                    r##"<rect x="{}" y="{}" width="{}" height="1" fill="#{:02x}{:02x}{:02x}"/>"##,
+                    x, y, run, color.r, color.g, color.b,
+                ).unwrap();
+                x += run;
+            }
+        }
+
+        res.push_str("</svg>\n");
+        res
+    }
+
+    /// Writes the picture as SVG or PPM depending on the extension of `path`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("svg") => fs::write(path, self.to_svg(CELL_SIZE)),
+            Some("ppm") => fs::write(path, self.to_ppm(CELL_SIZE)),
+            _ => Err(io::Error::new(io::ErrorKind::InvalidInput, "expected a `.svg` or `.ppm` file")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canvas() -> Canvas {
+        let grid = Grid::parse("#..\n.#.", Some).unwrap();
+        let mut canvas = Canvas::from_grid(&grid, |&c| if c == '#' { Color::WHITE } else { Color::BLACK });
+        canvas.overlay([Point::new(2, 1), Point::new(3, 1), Point::new(-1, 0)], Color::RED);
+        canvas
+    }
+
+    #[test]
+    fn ppm() {
+        let ppm = canvas().to_ppm(2);
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 6 * 4 * 3);
+        // Last pixel of the first row, then the last one of the picture
+        assert_eq!(ppm[header.len() + 5 * 3..header.len() + 6 * 3], [0, 0, 0]);
+        assert_eq!(ppm[ppm.len() - 3..], [220, 50, 47]);
+    }
+
+    #[test]
+    fn svg() {
+        let svg = canvas().to_svg(10);
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="20" viewBox="0 0 3 2""#));
+        assert!(svg.contains(r##"<rect x="1" y="0" width="2" height="1" fill="#000000"/>"##));
+        assert!(svg.contains(r##"<rect x="2" y="1" width="1" height="1" fill="#dc322f"/>"##));
+        assert_eq!(svg.matches("<rect").count(), 5);
+        assert!(canvas().save(Path::new("picture.png")).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+use crate::Answer;
+
+/// Answer of one part of a puzzle, with the time it took to compute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartReport {
+    pub part: usize,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+/// Result of solving one day.
+///
+/// Computing a report never prints anything: its `Display` implementation is the
+/// classic console output, other presentations only need to walk `parts`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub year: u32,
+    pub day: usize,
+    pub variant: Option<&'static str>,
+    pub title: &'static str,
+    /// Zero when the solution was built before being handed to [`crate::run`].
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl Report {
+    pub fn new(year: u32, day: usize, variant: Option<&'static str>, title: &'static str) -> Self {
+        Self {
+            year,
+            day,
+            variant,
+            title,
+            parse_time: Duration::ZERO,
+            parts: Vec::new(),
+        }
+    }
+
+    /// Name used on the command line, e.g. `10` or `10b`.
+    pub fn name(&self) -> String {
+        format!("{}{}", self.day, self.variant.unwrap_or(""))
+    }
+
+    pub fn answer(&self, part: usize) -> Option<&Answer> {
+        self.parts.iter().find(|report| report.part == part).map(|report| &report.answer)
+    }
+
+    /// Time spent parsing the input and solving every part.
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|report| report.time).sum::<Duration>()
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.variant {
+            Some(variant) => writeln!(f, "========= DAY {} ({}) ========", self.day, variant)?,
+            None => writeln!(f, "========= DAY {} ========", self.day)?,
+        }
+
+        for report in &self.parts {
+            writeln!(f, "Part {}: {} (took {:?})", report.part, report.answer, report.time)?;
+        }
+        writeln!(f)
+    }
+}
+
+/// Renders one line per day with every answer and timing.
+pub fn summary(reports: &[Report]) -> String {
+    let answer = |report: &Report, part| report.answer(part).map_or("-".to_string(), |answer| answer.to_string());
+
+    let width = reports.iter().map(|report| report.title.chars().count()).max().unwrap_or(0).max("Title".len());
+
+    let mut res = format!(
+        "{:>4}  {:<width$}  {:>18}  {:>18}  {:>10}  {:>10}\n",
+        "Day", "Title", "Part 1", "Part 2", "Parse", "Total",
+    );
+    for report in reports {
+        res += &format!(
+            "{:>4}  {:<width$}  {:>18}  {:>18}  {:>10.2?}  {:>10.2?}\n",
+            report.name(), report.title, answer(report, 1), answer(report, 2), report.parse_time, report.total_time(),
+        );
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_alignment() {
+        let mut long = Report::new(2023, 5, None, "If You Give A Seed A Fertilizer");
+        long.parts.push(PartReport { part: 1, answer: 35.into(), time: Duration::from_millis(2) });
+        let mut short = Report::new(2023, 10, Some("b"), "Pipe Maze");
+        short.parts.push(PartReport { part: 2, answer: "text".to_string().into(), time: Duration::from_micros(7) });
+
+        let summary = summary(&[long, short]);
+        let lines = summary.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| line.chars().count() == lines[0].chars().count()), "{}", summary);
+        assert_eq!(&lines[1][6..39], "If You Give A Seed A Fertilizer  ");
+        assert_eq!(&lines[2][..39], format!(" 10b  {:<33}", "Pipe Maze"));
+    }
+}
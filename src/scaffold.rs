@@ -0,0 +1,159 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::input::input_path;
+
+/// Skeleton of a solution, `REPLACEYEAR`, `REPLACEDAY` and `REPLACETITLE` standing for the year,
+/// the day and its title.
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// Generates `src/y<year>/dayN.rs` in the crate at `root`, declares it in `src/y<year>/mod.rs` and
+/// registers it in `src/lib.rs`, then creates an empty `inputs/<year>/dayN.txt` unless there is one
+/// already. The module of the year is created on its first day. Returns the files written.
+pub fn new_day(root: &Path, year: u32, day: usize, title: &str) -> io::Result<Vec<PathBuf>> {
+    let lib_path = root.join("src").join("lib.rs");
+    let year_dir = root.join("src").join(format!("y{}", year));
+    let modules_path = year_dir.join("mod.rs");
+
+    let mut lib = fs::read_to_string(&lib_path)?;
+    let mut modules = match fs::read_to_string(&modules_path) {
+        Ok(modules) => modules,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            lib = insert(&lib, format!("pub mod y{};", year), year, |line| number(line.strip_prefix("pub mod y")?))
+                .ok_or_else(|| io::Error::other("no `pub mod y<year>;` line in lib.rs"))?;
+            String::new()
+        },
+        Err(err) => return Err(err),
+    };
+
+    let module = format!("pub mod day{};", day);
+    if modules.lines().any(|line| line == module) {
+        return Err(io::Error::other(format!("day {} of {} is already registered", day, year)));
+    }
+    modules = insert(&modules, module.clone(), day, |line| number(line.strip_prefix("pub mod day")?))
+        .unwrap_or(module + "\n");
+
+    let entry = format!("    Day::of::<y{}::day{}::Solution>(),", year, day);
+    lib = insert(&lib, entry, (year, day), |line| {
+        let (entry_year, entry_day) = line.trim_start().strip_prefix("Day::of::<y")?.split_once("::day")?;
+        Some((number(entry_year)?, number(entry_day)?))
+    })
+    .ok_or_else(|| io::Error::other("no `DAYS` entry in lib.rs"))?;
+
+    let source = year_dir.join(format!("day{}.rs", day));
+    let code = TEMPLATE
+        .replace("REPLACEYEAR", &year.to_string())
+        .replace("REPLACEDAY", &day.to_string())
+        .replace("\"REPLACETITLE\"", &format!("{:?}", title));
+    fs::create_dir_all(&year_dir)?;
+    OpenOptions::new().write(true).create_new(true).open(&source)?.write_all(code.as_bytes())?;
+    fs::write(&modules_path, modules)?;
+    fs::write(&lib_path, lib)?;
+    let mut written = vec![source, modules_path, lib_path];
+
+    let inputs = root.join("inputs").join(year.to_string());
+    fs::create_dir_all(&inputs)?;
+    let input = input_path(&inputs, day);
+    match OpenOptions::new().write(true).create_new(true).open(&input) {
+        Ok(_) => written.push(input),
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {},
+        Err(err) => return Err(err),
+    }
+    Ok(written)
+}
+
+/// Leading number of `text`, e.g. 10 in `10b;`.
+fn number<T: std::str::FromStr>(text: &str) -> Option<T> {
+    text[..text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len())].parse().ok()
+}
+
+/// Inserts `line` into `source` right after the last line whose key is at most `key`, or before
+/// the first keyed line. `None` when no line has a key.
+fn insert<K: Ord>(source: &str, line: String, key: K, line_key: impl Fn(&str) -> Option<K>) -> Option<String> {
+    let mut lines = source.lines().map(str::to_string).collect::<Vec<_>>();
+    let keyed = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, line_key(line)?)))
+        .collect::<Vec<_>>();
+
+    let index = match keyed.iter().rev().find(|(_, other)| *other <= key) {
+        Some((index, _)) => index + 1,
+        None => keyed.first()?.0,
+    };
+    lines.insert(index, line);
+
+    let mut res = lines.join("\n");
+    if source.ends_with('\n') {
+        res.push('\n');
+    }
+    Some(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const LIB: &str = "\
+pub mod y2023;
+
+pub static DAYS: &[Day] = &[
+    Day::of::<y2023::day1::Solution>(),
+    Day::of::<y2023::day10::Solution>(),
+    Day::of::<y2023::day10b::Solution>(),
+];
+";
+
+    fn day_key(line: &str) -> Option<usize> {
+        number(line.strip_prefix("pub mod day")?)
+    }
+
+    #[test]
+    fn modules() {
+        let modules = "pub mod day1;\npub mod day3;\npub mod day10;\n";
+        assert_eq!(insert(modules, "pub mod day2;".into(), 2, day_key).unwrap(), "pub mod day1;\npub mod day2;\npub mod day3;\npub mod day10;\n");
+        assert_eq!(insert(modules, "pub mod day11;".into(), 11, day_key).unwrap(), "pub mod day1;\npub mod day3;\npub mod day10;\npub mod day11;\n");
+        assert_eq!(insert("// days\npub mod day3;", "pub mod day1;".into(), 1, day_key).unwrap(), "// days\npub mod day1;\npub mod day3;");
+        assert_eq!(insert("", "pub mod day1;".into(), 1, day_key), None);
+    }
+
+    #[test]
+    fn registry() {
+        let entry_key = |line: &str| {
+            let (year, day) = line.trim_start().strip_prefix("Day::of::<y")?.split_once("::day")?;
+            Some((number::<u32>(year)?, number::<usize>(day)?))
+        };
+        let lib = insert(LIB, "    Day::of::<y2023::day11::Solution>(),".into(), (2023, 11), entry_key).unwrap();
+        assert!(lib.contains("day10b::Solution>(),\n    Day::of::<y2023::day11::Solution>(),\n];"));
+        let lib = insert(LIB, "    Day::of::<y2022::day5::Solution>(),".into(), (2022, 5), entry_key).unwrap();
+        assert!(lib.contains("&[\n    Day::of::<y2022::day5::Solution>(),\n    Day::of::<y2023::day1::Solution>(),"));
+    }
+
+    #[test]
+    fn new_days() {
+        let root = env::temp_dir().join(format!("advent-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src").join("y2023")).unwrap();
+        fs::write(root.join("src").join("lib.rs"), LIB).unwrap();
+        fs::write(root.join("src").join("y2023").join("mod.rs"), "pub mod day1;\npub mod day10;\n").unwrap();
+
+        let written = new_day(&root, 2023, 2, "Cube \"Conundrum\"").unwrap();
+        assert_eq!(written.len(), 4);
+        let source = fs::read_to_string(root.join("src/y2023/day2.rs")).unwrap();
+        assert!(source.contains("const DAY: usize = 2;") && source.contains(r#""Cube \"Conundrum\"""#));
+        assert_eq!(fs::read_to_string(root.join("src/y2023/mod.rs")).unwrap(), "pub mod day1;\npub mod day2;\npub mod day10;\n");
+        assert!(fs::read_to_string(root.join("src/lib.rs")).unwrap().contains("day1::Solution>(),\n    Day::of::<y2023::day2::Solution>(),"));
+        assert_eq!(fs::read_to_string(root.join("inputs/2023/day2.txt")).unwrap(), "");
+        assert!(new_day(&root, 2023, 2, "Again").is_err());
+
+        new_day(&root, 2024, 1, "Next year").unwrap();
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert!(lib.starts_with("pub mod y2023;\npub mod y2024;\n"));
+        assert!(lib.contains("day10b::Solution>(),\n    Day::of::<y2024::day1::Solution>(),\n];"));
+        assert_eq!(fs::read_to_string(root.join("src/y2024/mod.rs")).unwrap(), "pub mod day1;\n");
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,256 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use priority_queue::PriorityQueue;
+
+/// Outcome of a search: the distance from the closest start to every reached state, and the state
+/// each one was reached from.
+#[derive(Debug, Clone)]
+pub struct Search<S, C = usize> {
+    distances: HashMap<S, C>,
+    parents: HashMap<S, S>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new() -> Self {
+        Self { distances: HashMap::new(), parents: HashMap::new(), goal: None }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    /// Every reached state, including the starts.
+    pub fn states(&self) -> impl Iterator<Item = &S> {
+        self.distances.keys()
+    }
+
+    pub fn contains(&self, state: &S) -> bool {
+        self.distances.contains_key(state)
+    }
+
+    /// `None` for the starts.
+    pub fn parent(&self, state: &S) -> Option<&S> {
+        self.parents.get(state)
+    }
+
+    /// Goal state the search stopped on, `None` when it ran out of states first.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// States from a start to `state`, both included.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        if !self.contains(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(parent) = self.parent(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search over every state reachable from `starts`.
+pub fn bfs<S, I>(starts: impl IntoIterator<Item = S>, successors: impl FnMut(&S) -> I) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_until(starts, successors, |_| false)
+}
+
+/// Breadth-first search stopping as soon as a state matching `is_goal` is taken out of the queue,
+/// which is then at the shortest distance of all goal states.
+pub fn bfs_until<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.contains(&start) {
+            search.distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        let distance = search.distances[&state];
+        for next in successors(&state) {
+            if !search.contains(&next) {
+                search.distances.insert(next.clone(), distance + 1);
+                search.parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Shortest paths for successors given with the cost of the step to them, stopping on the first
+/// goal state like [`bfs_until`]. Costs must not be negative.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// [`dijkstra`] guided by `heuristic`, an estimate of the remaining cost to a goal that must never
+/// exceed the actual one.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new();
+    let mut queue = PriorityQueue::new();
+    for start in starts {
+        search.distances.insert(start.clone(), C::default());
+        queue.push(start.clone(), Reverse(heuristic(&start)));
+    }
+
+    while let Some((state, _)) = queue.pop() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        let distance = search.distances[&state];
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            match search.distances.entry(next.clone()) {
+                Entry::Occupied(entry) if *entry.get() <= next_distance => continue,
+                Entry::Occupied(mut entry) => *entry.get_mut() = next_distance,
+                Entry::Vacant(entry) => {
+                    entry.insert(next_distance);
+                },
+            }
+            search.parents.insert(next.clone(), state.clone());
+            // Updates the priority when `next` is queued already
+            let priority = Reverse(next_distance + heuristic(&next));
+            queue.push(next, priority);
+        }
+    }
+
+    search
+}
+
+/// Cost of the longest path from `start` to a goal state that never visits a state twice, by
+/// trying them all: only fit for small graphs, such as those between the crossings of a maze.
+pub fn longest_path<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = HashSet::from([start.clone()]);
+    let mut longest = None;
+    extend_path(&start, C::default(), &mut visited, &mut longest, &mut successors, &mut is_goal);
+    longest
+}
+
+fn extend_path<S, C, I>(
+    state: &S,
+    length: C,
+    visited: &mut HashSet<S>,
+    longest: &mut Option<C>,
+    successors: &mut impl FnMut(&S) -> I,
+    is_goal: &mut impl FnMut(&S) -> bool,
+) where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    if is_goal(state) {
+        *longest = Some(longest.map_or(length, |longest| longest.max(length)));
+        return;
+    }
+
+    for (next, cost) in successors(state) {
+        if visited.insert(next.clone()) {
+            extend_path(&next, length + cost, visited, longest, successors, is_goal);
+            visited.remove(&next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3 and 0 -> 2 -> 3, the way through 2 being cheaper
+    fn edges(state: &usize) -> Vec<(usize, usize)> {
+        match state {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(3, 10)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_paths() {
+        let search = bfs([0], |&state| edges(&state).into_iter().map(|(next, _)| next));
+        assert_eq!(search.distance(&3), Some(2));
+        assert_eq!(search.path(&3), Some(vec![0, 1, 3]));
+        assert_eq!(search.path(&4), None);
+        assert_eq!(search.goal(), None);
+
+        let search = bfs_until([0], |&state| [state + 1, state * 2], |&state| state == 10);
+        assert_eq!(search.goal(), Some(&10));
+        assert_eq!(search.path(&10), Some(vec![0, 1, 2, 4, 5, 10]));
+    }
+
+    #[test]
+    fn weighted_paths() {
+        let search = dijkstra([0], edges, |&state| state == 3);
+        assert_eq!(search.distance(&3), Some(6));
+        assert_eq!(search.path(&3), Some(vec![0, 2, 3]));
+
+        let search = astar([0usize], |&state| [(state + 1, 1), (state + 7, 5)], |&state| 20usize.saturating_sub(state) * 5 / 7, |&state| state == 20);
+        assert_eq!(search.distance(&20), Some(16));
+
+        assert_eq!(longest_path(0, edges, |&state| state == 3), Some(11));
+        assert_eq!(longest_path(1, edges, |&state| state == 2), None);
+    }
+}
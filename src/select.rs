@@ -0,0 +1,67 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::error::parse_number;
+use crate::ParseError;
+
+/// Days given on the command line, as a comma separated list of days or inclusive ranges,
+/// e.g. `5,22` or `10..15`. A day matches all of its variants.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DaySet {
+    ranges: Vec<RangeInclusive<usize>>,
+}
+
+impl DaySet {
+    pub fn contains(&self, day: usize) -> bool {
+        self.ranges.iter().any(|range| range.contains(&day))
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let ranges = value
+            .split(',')
+            .map(|item| match item.split_once("..") {
+                Some((start, end)) => Ok(parse_number(start)?..=parse_number(end.strip_prefix('=').unwrap_or(end))?),
+                None => parse_number(item).map(|day| day..=day),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { ranges })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(value: &str) -> Vec<usize> {
+        let set = value.parse::<DaySet>().unwrap();
+        (0..=30).filter(|&day| set.contains(day)).collect()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(days("5"), [5]);
+        assert_eq!(days("5,22"), [5, 22]);
+        assert_eq!(days("10..13"), [10, 11, 12, 13]);
+        assert_eq!(days("10..=13"), [10, 11, 12, 13]);
+        assert_eq!(days("1..2,7,24..25"), [1, 2, 7, 24, 25]);
+        assert_eq!(days("3..1"), Vec::<usize>::new());
+        assert!(!DaySet::default().contains(1));
+    }
+
+    #[test]
+    fn invalid() {
+        let text = |value: &str| value.parse::<DaySet>().unwrap_err().text;
+        assert_eq!(text(""), "");
+        assert_eq!(text("x"), "x");
+        assert_eq!(text("5,"), "");
+        assert_eq!(text("1..x"), "x");
+        assert_eq!(text("..3"), "");
+        assert_eq!(text("-1"), "-1");
+        assert_eq!(text("1...3"), ".3");
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt::{self, Display};
+use std::fs::read_to_string;
+use std::time::Instant;
+
+use crate::error::ParseError;
+use crate::input::{input_path, inputs_dir, normalize};
+use crate::render::Canvas;
+use crate::report::{PartReport, Report};
+use crate::trace::Tracer;
+
+/// Answer produced by one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// No answer, for the given reason.
+    Skipped(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Skipped(reason) => write!(f, "Skipped ({})", reason),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(i64::try_from(value).expect("answer does not fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u32, i32, u64, i64, usize, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Common interface of every `dayN::Solution`.
+pub trait Solver {
+    const YEAR: u32;
+    const DAY: usize;
+    const TITLE: &'static str;
+    /// Distinguishes alternative solutions of the same day (e.g. `day10b`).
+    const VARIANT: Option<&'static str> = None;
+
+    fn parse(input: &str) -> Result<Self, ParseError> where Self: Sized;
+
+    /// Builds the solution from an in-memory puzzle input, once [normalized](normalize).
+    fn from_input(input: &str) -> Result<Self, ParseError> where Self: Sized {
+        let input = normalize(input);
+        Self::parse(&input).map_err(|err| err.locate(&input))
+    }
+
+    /// Builds the solution from `inputs/<year>/dayN.txt`.
+    fn init() -> Self where Self: Sized {
+        let input = read_to_string(input_path(&inputs_dir(Self::YEAR), Self::DAY)).unwrap();
+        Self::from_input(&input).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn part1(&mut self) -> Answer;
+    fn part2(&mut self) -> Answer;
+
+    /// Picture of the state of the solution, for the days where there is something to look at.
+    /// Called after the parts, so that it can show what they computed.
+    fn render(&mut self) -> Option<Canvas> {
+        None
+    }
+
+    /// Hook the simulations of the day report their steps to, for the days that have some.
+    fn tracer(&mut self) -> Option<&mut Tracer> {
+        None
+    }
+
+    /// Solves both parts, see [`run`].
+    fn solve(&mut self) -> Report where Self: Sized {
+        run(self, &[1, 2])
+    }
+}
+
+/// Object-safe view of a [`Solver`], so that every day can be stored in the same table.
+pub trait DynSolver {
+    fn year(&self) -> u32;
+    fn day(&self) -> usize;
+    fn variant(&self) -> Option<&'static str>;
+    fn title(&self) -> &'static str;
+    fn part(&mut self, part: usize) -> Answer;
+    fn render(&mut self) -> Option<Canvas>;
+    fn tracer(&mut self) -> Option<&mut Tracer>;
+}
+
+impl<S: Solver> DynSolver for S {
+    fn year(&self) -> u32 {
+        S::YEAR
+    }
+
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn variant(&self) -> Option<&'static str> {
+        S::VARIANT
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn part(&mut self, part: usize) -> Answer {
+        match part {
+            1 => self.part1(),
+            2 => self.part2(),
+            _ => panic!("Unknown part {}", part),
+        }
+    }
+
+    fn render(&mut self) -> Option<Canvas> {
+        Solver::render(self)
+    }
+
+    fn tracer(&mut self) -> Option<&mut Tracer> {
+        Solver::tracer(self)
+    }
+}
+
+/// Runs the given parts of a solution, timing each of them.
+pub fn run(solver: &mut dyn DynSolver, parts: &[usize]) -> Report {
+    let mut report = Report::new(solver.year(), solver.day(), solver.variant(), solver.title());
+    for &part in parts {
+        let start = Instant::now();
+        let answer = solver.part(part);
+        let time = start.elapsed();
+        report.parts.push(PartReport { part, answer, time });
+    }
+    report
+}
+
+/// Entry of the day registry.
+pub struct Day {
+    pub year: u32,
+    pub day: usize,
+    pub variant: Option<&'static str>,
+    pub title: &'static str,
+    parse: fn(&str) -> Result<Box<dyn DynSolver>, ParseError>,
+}
+
+fn parse_boxed<S: Solver + 'static>(input: &str) -> Result<Box<dyn DynSolver>, ParseError> {
+    Ok(Box::new(S::from_input(input)?))
+}
+
+impl Day {
+    pub const fn of<S: Solver + 'static>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            variant: S::VARIANT,
+            title: S::TITLE,
+            parse: parse_boxed::<S>,
+        }
+    }
+
+    /// Name used on the command line, e.g. `10` or `10b`.
+    pub fn name(&self) -> String {
+        format!("{}{}", self.day, self.variant.unwrap_or(""))
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn DynSolver>, ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Parses `input` and runs the given parts, timing the parsing as well.
+    pub fn solve(&self, input: &str, parts: &[usize]) -> Result<Report, ParseError> {
+        let start = Instant::now();
+        let mut solver = self.parse(input)?;
+        let parse_time = start.elapsed();
+
+        let mut report = run(solver.as_mut(), parts);
+        report.parse_time = parse_time;
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(Answer::from(u64::MAX >> 1), Answer::Number(i64::MAX));
+        assert_eq!(Answer::from(-3isize), Answer::Number(-3));
+    }
+
+    #[test]
+    #[should_panic(expected = "answer does not fit in an i64")]
+    fn too_large() {
+        let _ = Answer::from(u64::MAX);
+    }
+}
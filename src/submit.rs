@@ -0,0 +1,290 @@
+use std::env;
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::error::{parse_number, split_once};
+use crate::fetch::{Http, Ureq, BASE_URL, BASE_URL_VAR, SESSION_VAR};
+use crate::ParseError;
+
+/// `submissions.log` at the root of the crate, next to `answers.toml`.
+pub fn log_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("submissions.log")
+}
+
+/// Reply of the website to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Another answer was sent too recently, with the remaining wait when given.
+    RateLimited(Option<String>),
+    /// The part is solved already, or not unlocked yet.
+    WrongLevel,
+    /// Text of a reply that could not be understood.
+    Unknown(String),
+}
+
+impl Verdict {
+    pub fn parse(body: &str) -> Self {
+        // The message is the only `<article>` of the page
+        let text = body
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(body, |(article, _)| article);
+        let text = strip_tags(text);
+
+        if text.contains("That's the right answer") {
+            Verdict::Right
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Verdict::RateLimited(wait)
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if text.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(text.split_whitespace().collect::<Vec<_>>().join(" "))
+        }
+    }
+
+    /// Whether the verdict says something about the answer itself, and so belongs in the log.
+    pub fn is_final(&self) -> bool {
+        matches!(self, Verdict::Right | Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::RateLimited(_) => "rate-limited",
+            Verdict::WrongLevel => "wrong-level",
+            Verdict::Unknown(_) => "unknown",
+        }
+    }
+
+    fn from_name(name: &str) -> Result<Self, ParseError> {
+        match name {
+            "right" => Ok(Verdict::Right),
+            "wrong" => Ok(Verdict::Wrong),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            _ => Err(ParseError::new("expected one of `right`, `wrong`, `too-high`, `too-low`", name)),
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "That's the right answer!"),
+            Verdict::Wrong => write!(f, "That's not the right answer."),
+            Verdict::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            Verdict::TooLow => write!(f, "That's not the right answer, it is too low."),
+            Verdict::RateLimited(Some(wait)) => write!(f, "Answered too recently, {} left to wait.", wait),
+            Verdict::RateLimited(None) => write!(f, "Answered too recently."),
+            Verdict::WrongLevel => write!(f, "This part is either solved already or still locked."),
+            Verdict::Unknown(text) => write!(f, "Unexpected reply: {}", text),
+        }
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut res = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => res.push(c),
+            _ => {},
+        }
+    }
+    res
+}
+
+/// One answer sent to the website and its verdict.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub year: u32,
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Previous guesses, one `year,day,part,verdict,answer` line each, the answer coming last as it
+/// may contain commas.
+#[derive(Debug, Clone, Default)]
+pub struct Log {
+    pub guesses: Vec<Guess>,
+}
+
+impl Log {
+    /// A missing file is an empty log.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content).map_err(|err| err.to_string()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_unlocated(input).map_err(|err| err.locate(input))
+    }
+
+    fn parse_unlocated(input: &str) -> Result<Self, ParseError> {
+        let mut guesses = Vec::new();
+        for line in input.lines().filter(|line| !line.is_empty() && !line.starts_with('#')) {
+            let (year, rest) = split_once(line, ",")?;
+            let (day, rest) = split_once(rest, ",")?;
+            let (part, rest) = split_once(rest, ",")?;
+            let (verdict, answer) = split_once(rest, ",")?;
+            guesses.push(Guess {
+                year: parse_number(year)?,
+                day: parse_number(day)?,
+                part: parse_number(part)?,
+                answer: answer.to_string(),
+                verdict: Verdict::from_name(verdict)?,
+            });
+        }
+
+        Ok(Self { guesses })
+    }
+
+    /// Appends a guess to the log file.
+    pub fn record(&mut self, path: &Path, guess: Guess) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{},{},{},{},{}", guess.year, guess.day, guess.part, guess.verdict.name(), guess.answer)?;
+        self.guesses.push(guess);
+        Ok(())
+    }
+
+    /// Why `answer` is known to be wrong, or not worth sending, according to the previous guesses.
+    pub fn refusal(&self, year: u32, day: usize, part: usize, answer: &str) -> Option<String> {
+        let number = answer.parse::<i64>().ok();
+        self.guesses
+            .iter()
+            .filter(|guess| guess.year == year && guess.day == day && guess.part == part)
+            .find_map(|guess| {
+                let bound = guess.answer.parse::<i64>().ok().zip(number);
+                match guess.verdict {
+                    Verdict::Right if guess.answer == answer => Some("it was already accepted".to_string()),
+                    Verdict::Right => Some(format!("the part is solved already, with {}", guess.answer)),
+                    _ if guess.answer == answer => Some("it was already rejected".to_string()),
+                    Verdict::TooHigh if bound.is_some_and(|(bound, number)| number >= bound) => {
+                        Some(format!("{} was already too high", guess.answer))
+                    },
+                    Verdict::TooLow if bound.is_some_and(|(bound, number)| number <= bound) => {
+                        Some(format!("{} was already too low", guess.answer))
+                    },
+                    _ => None,
+                }
+            })
+    }
+}
+
+/// Sends answers to the website.
+#[derive(Debug, Clone)]
+pub struct Submitter<H = Ureq> {
+    http: H,
+    session: String,
+    base_url: String,
+}
+
+impl Submitter {
+    pub fn new(session: impl Into<String>) -> Self {
+        Self { http: Ureq, session: session.into(), base_url: BASE_URL.to_string() }
+    }
+
+    /// Reads the session from [`SESSION_VAR`], and the base URL from [`BASE_URL_VAR`] when set.
+    pub fn from_env() -> Result<Self, String> {
+        let session = env::var(SESSION_VAR).map_err(|_| format!("{} is not set", SESSION_VAR))?;
+        let submitter = Self::new(session.trim());
+        Ok(match env::var(BASE_URL_VAR) {
+            Ok(url) => submitter.base_url(url),
+            Err(_) => submitter,
+        })
+    }
+}
+
+impl<H: Http> Submitter<H> {
+    pub fn http<T: Http>(self, http: T) -> Submitter<T> {
+        Submitter { http, session: self.session, base_url: self.base_url }
+    }
+
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn submit(&self, year: u32, day: usize, part: usize, answer: &str) -> io::Result<Verdict> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let body = self.http.post(&url, &self.session, &[("level", &part.to_string()), ("answer", answer)])?;
+        Ok(Verdict::parse(&body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reply(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>", message)
+    }
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(
+            Verdict::parse(&reply("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")),
+            Verdict::Right,
+        );
+        assert_eq!(
+            Verdict::parse(&reply("That's not the right answer; your answer is too high.  If you're stuck, ...")),
+            Verdict::TooHigh,
+        );
+        assert_eq!(
+            Verdict::parse(&reply("That's not the right answer; your answer is too low.")),
+            Verdict::TooLow,
+        );
+        assert_eq!(
+            Verdict::parse(&reply("That's not the right answer.  If you're stuck, make sure you're using the full input data")),
+            Verdict::Wrong,
+        );
+        assert_eq!(
+            Verdict::parse(&reply("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a>")),
+            Verdict::RateLimited(Some("34s".to_string())),
+        );
+        assert_eq!(
+            Verdict::parse(&reply("You don't seem to be solving the right level.  Did you already complete it?")),
+            Verdict::WrongLevel,
+        );
+    }
+
+    #[test]
+    fn refusals() {
+        let log = Log::parse("2023,5,1,too-high,500\n2023,5,1,too-low,100\n2023,5,1,wrong,300\n2023,6,1,right,42\n").unwrap();
+        assert!(log.refusal(2023, 5, 1, "300").is_some());
+        assert!(log.refusal(2023, 5, 1, "600").is_some());
+        assert!(log.refusal(2023, 5, 1, "50").is_some());
+        assert!(log.refusal(2023, 5, 1, "250").is_none());
+        assert!(log.refusal(2023, 5, 2, "300").is_none());
+        assert!(log.refusal(2023, 6, 1, "41").is_some());
+        assert!(log.refusal(2022, 5, 1, "300").is_none());
+    }
+}
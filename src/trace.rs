@@ -0,0 +1,264 @@
+use std::cell::RefCell;
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::rc::Rc;
+
+use crate::format::json_string;
+use crate::geometry::{Direction, Point};
+
+/// Value of an event field, written as JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Bool(bool),
+    Number(i64),
+    Text(String),
+    List(Vec<Value>),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Text(text) => write!(f, "{}", json_string(text)),
+            Value::List(values) => {
+                write!(f, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            },
+        }
+    }
+}
+
+macro_rules! value_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Value {
+                fn from(value: $t) -> Self {
+                    Value::Number(i64::try_from(value).expect("traced number does not fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+value_from_number!(u32, i32, u64, i64, usize, isize);
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Self {
+        Value::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+/// `[x, y]`
+impl From<Point> for Value {
+    fn from(point: Point) -> Self {
+        Value::List(vec![point.x.into(), point.y.into()])
+    }
+}
+
+impl From<Direction> for Value {
+    fn from(direction: Direction) -> Self {
+        Value::Text(format!("{:?}", direction))
+    }
+}
+
+/// Something that happened during one step of a simulation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub name: &'static str,
+    pub fields: Vec<(&'static str, Value)>,
+}
+
+impl Event {
+    pub fn new(name: &'static str) -> Self {
+        Self { name, fields: vec![] }
+    }
+
+    pub fn with(mut self, key: &'static str, value: impl Into<Value>) -> Self {
+        self.fields.push((key, value.into()));
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.fields.iter().find(|(other, _)| *other == key).map(|(_, value)| value)
+    }
+}
+
+/// Receives the steps of a simulation as they happen.
+pub trait Observer {
+    fn observe(&mut self, event: &Event) -> io::Result<()>;
+
+    /// Called once the simulation is over.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writes each event as a JSON object on its own line, numbered by a `step` field, so that two runs
+/// can be compared with `diff`.
+pub struct JsonLines<W: Write> {
+    out: W,
+    step: usize,
+}
+
+impl<W: Write> JsonLines<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, step: 0 }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+impl JsonLines<BufWriter<File>> {
+    pub fn create(path: &Path) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> Observer for JsonLines<W> {
+    fn observe(&mut self, event: &Event) -> io::Result<()> {
+        write!(self.out, "{{\"step\":{},\"event\":{}", self.step, json_string(event.name))?;
+        for (key, value) in &event.fields {
+            write!(self.out, ",{}:{}", json_string(key), value)?;
+        }
+        writeln!(self.out, "}}")?;
+        self.step += 1;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+/// Keeps the events in memory, in tests for instance. Clones share the same events.
+#[derive(Debug, Clone, Default)]
+pub struct Recorder {
+    events: Rc<RefCell<Vec<Event>>>,
+}
+
+impl Recorder {
+    pub fn events(&self) -> Vec<Event> {
+        self.events.borrow().clone()
+    }
+}
+
+impl Observer for Recorder {
+    fn observe(&mut self, event: &Event) -> io::Result<()> {
+        self.events.borrow_mut().push(event.clone());
+        Ok(())
+    }
+}
+
+/// Hook the simulations report their steps to. It does nothing until an observer is attached, the
+/// events are not even built.
+#[derive(Default)]
+pub struct Tracer {
+    observer: RefCell<Option<Box<dyn Observer>>>,
+    error: RefCell<Option<io::Error>>,
+}
+
+impl Tracer {
+    pub fn attach(&mut self, observer: Box<dyn Observer>) {
+        self.observer = RefCell::new(Some(observer));
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.observer.borrow().is_some()
+    }
+
+    /// Takes `&self` so that it can be called from the simulations borrowing their solution.
+    pub fn emit(&self, event: impl FnOnce() -> Event) {
+        if let Some(observer) = self.observer.borrow_mut().as_mut() {
+            // Keep the first error, the following ones are likely the same
+            if let Err(err) = observer.observe(&event()) {
+                self.error.borrow_mut().get_or_insert(err);
+            }
+        }
+    }
+
+    /// Finishes the observer, then reports the first error it ran into.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if let Some(observer) = self.observer.get_mut().as_mut() {
+            observer.finish()?;
+        }
+        match self.error.get_mut().take() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_lines() {
+        let mut observer = JsonLines::new(vec![]);
+        let event = Event::new("move")
+            .with("at", Point::new(1, -2))
+            .with("facing", Direction::Left)
+            .with("label", "a \"b\"")
+            .with("seen", vec![true, false]);
+        observer.observe(&event).unwrap();
+        observer.observe(&Event::new("stop")).unwrap();
+
+        assert_eq!(
+            String::from_utf8(observer.into_inner()).unwrap(),
+            "{\"step\":0,\"event\":\"move\",\"at\":[1,-2],\"facing\":\"Left\",\"label\":\"a \\\"b\\\"\",\"seen\":[true,false]}\n\
+             {\"step\":1,\"event\":\"stop\"}\n",
+        );
+    }
+
+    #[test]
+    fn tracer() {
+        let mut tracer = Tracer::default();
+        tracer.emit(|| panic!("Events are only built when observed"));
+        assert!(!tracer.is_enabled());
+
+        let recorder = Recorder::default();
+        tracer.attach(Box::new(recorder.clone()));
+        tracer.emit(|| Event::new("tick").with("n", 1));
+        tracer.emit(|| Event::new("tick").with("n", 2));
+        tracer.finish().unwrap();
+
+        let events = recorder.events();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].get("n"), Some(&Value::Number(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "traced number does not fit in an i64")]
+    fn too_large() {
+        let _ = Value::from(usize::MAX);
+    }
+}
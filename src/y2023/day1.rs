@@ -0,0 +1,142 @@
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    lines: Vec<String>,
+}
+
+impl Solution {
+    fn text_to_digit(line: &str) -> Option<u32> {
+        match line {
+            line if line.starts_with("one") => Some(1),
+            line if line.starts_with("two") => Some(2),
+            line if line.starts_with("three") => Some(3),
+            line if line.starts_with("four") => Some(4),
+            line if line.starts_with("five") => Some(5),
+            line if line.starts_with("six") => Some(6),
+            line if line.starts_with("seven") => Some(7),
+            line if line.starts_with("eight") => Some(8),
+            line if line.starts_with("nine") => Some(9),
+            _ => None
+        }
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+        for line in input.lines() {
+            // Part 2 also reads spelled out digits
+            if !line.char_indices().any(|(i, c)| c.is_ascii_digit() || Self::text_to_digit(&line[i..]).is_some()) {
+                return Err(ParseError::new("expected a digit", line));
+            }
+            lines.push(line.to_string());
+        }
+
+        Ok(Self {
+            lines,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        let mut total = 0;
+
+        for line in &self.lines {
+            let mut first_digit = Option::None;
+            let mut last_digit = Option::None;
+
+            for c in line.chars() {
+                if c.is_digit(10) {
+                    let d = c.to_digit(10).unwrap();
+                    if first_digit.is_none() {
+                        first_digit = Some(d)
+                    }
+                    last_digit = Some(d);
+                }
+            }
+
+            // Only spelled out digits are left for part 2
+            let (Some(first_digit), Some(last_digit)) = (first_digit, last_digit) else {
+                return Answer::Skipped("a line has no digit".to_string());
+            };
+
+            total += first_digit * 10 + last_digit;
+        }
+
+        total.into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        let mut total = 0;
+
+        for line in &self.lines {
+            let mut first_digit = Option::None;
+            let mut last_digit = Option::None;
+
+            for (i, c) in line.char_indices() {
+                let d = if c.is_digit(10) {
+                    c.to_digit(10)
+                } else {
+                    Self::text_to_digit(&line[i..])
+                };
+
+                if let Some(d) = d {
+                    if first_digit.is_none() {
+                        first_digit = Some(d)
+                    }
+                    last_digit = Some(d);
+                }
+            }
+
+            // parse checked every line has a digit
+            total += first_digit.unwrap() * 10 + last_digit.unwrap();
+        }
+
+        total.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+    const EXAMPLE_2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE_1).unwrap().part1(), 142.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE_2).unwrap().part2(), 281.into());
+    }
+
+    #[test]
+    fn spelled_out_only() {
+        assert_eq!(Solution::from_input(EXAMPLE_2).unwrap().part1(), Answer::Skipped("a line has no digit".to_string()));
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("1abc2\nabc\n3"), "abc");
+        assert_eq!(error("on\ne"), "on");
+    }
+}
\ No newline at end of file
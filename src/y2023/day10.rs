@@ -0,0 +1,252 @@
+
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{parse_number, split_once};
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::render::{Canvas, Color};
+use crate::search::bfs;
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    map: Grid<char>,
+    char_to_direction: HashMap<char, Vec<Direction>>,
+    direction_to_pipes: HashMap<Direction, HashSet<char>>,
+    start: Point,
+    path: HashSet<Point>,
+    farthest: usize,
+}
+
+
+
+impl Solution {
+    fn get_char(&self, pos: Point) -> char {
+        self.map[pos]
+    }
+
+    fn find_neighboors(&self, from: Point) -> Vec<Point> {
+        let mut res = Vec::with_capacity(2);
+
+        let from_char = self.get_char(from);
+
+        for &direction in &self.char_to_direction[&from_char]{
+            let possible_pipes = &self.direction_to_pipes[&direction];
+            let new_position = from + direction;
+            if self.map.get(new_position).is_some_and(|c| possible_pipes.contains(c)) {
+                res.push(new_position);
+            } 
+        }
+
+        res
+    }
+
+    /// Adds the tiles left and right of `current` when heading in `direction` to the two sides.
+    fn add_sides(&self, current: Point, direction: Direction, side1: &mut HashSet<Point>, side2: &mut HashSet<Point>) {
+        let left = current + direction.turn_left();
+        if !self.path.contains(&left) {
+            side1.insert(left);
+        }
+        let right = current + direction.turn_right();
+        if !self.path.contains(&right) {
+            side2.insert(right);
+        }
+    }
+
+    /// Tiles enclosed by the loop.
+    fn inside(&self) -> Vec<Point> {
+        use Direction::*;
+        let dir_change_chars = vec!['F', 'J', '7', 'L'];
+        let mut direction_changers: HashMap<(char, Direction), Direction> = HashMap::new();
+        direction_changers.insert(('F', Up), Right);
+        direction_changers.insert(('F', Left), Down);
+        direction_changers.insert(('7', Up), Left);
+        direction_changers.insert(('7', Right), Down);
+        direction_changers.insert(('J', Down), Left);
+        direction_changers.insert(('J', Right), Up);
+        direction_changers.insert(('L', Down), Right);
+        direction_changers.insert(('L', Left), Up);
+
+        // Go through the path and compute two different sides, left and right of the direction
+        let mut direction = Up;
+        let first = *self.path.iter().find(|pos| self.get_char(**pos) == '|').unwrap();
+        let mut current = first + direction;
+
+        let mut side1 = HashSet::new();
+        let mut side2 = HashSet::new();
+
+        while current != first {
+            let c = self.get_char(current);
+            self.add_sides(current, direction, &mut side1, &mut side2);
+
+            // Change the direction
+            if dir_change_chars.contains(&c) {
+                direction = direction_changers[&(c, direction)];
+
+                // Do it again
+                self.add_sides(current, direction, &mut side1, &mut side2);
+            }
+            current += direction;
+        }
+
+        // Now we must figure out which one is outside, and which one is outside
+        // Let's find the one the most above, must be outside
+        let side1_min = side1.iter().map(|p| p.y).min().unwrap_or(9999);
+        let side2_min = side2.iter().map(|p| p.y).min().unwrap_or(9999);
+
+        let (inside, _outside) = if side1_min < side2_min {
+            (side2, side1)
+        } else {
+            (side1, side2)
+        };
+
+        // From all the points "inside", run a kind of percolation/DFS to expand the inside
+        let inside = bfs(inside, |position| {
+            position.neighbors4().filter(|new_position| self.map.contains(*new_position) && !self.path.contains(new_position))
+        });
+        inside.states().copied().collect()
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        // INPUT NEEDS TO BE CHANGED: CHANGE START BY ACTUAL PIPE FORMAT, ADD START POSITION AT THE TOP OF THE FILE
+        // SOMETIMES ADD PADDING TOP/BOTTOM + UPDATE STARTY ACCORDINGLY...
+        let (start_str, map) = split_once(input, "\n")?;
+        let (x, y) = split_once(start_str.trim(), ",")?;
+        let start: (isize, isize) = (parse_number(x)?, parse_number(y)?);
+        let map = Grid::parse(map, Some)?;
+
+        use Direction::*;
+        let mut char_to_direction: HashMap<char, Vec<Direction>> = HashMap::new();
+        char_to_direction.insert('S', vec![Right, Left, Down, Up]);
+        char_to_direction.insert('|', vec![Down, Up]);
+        char_to_direction.insert('-', vec![Right, Left]);
+        char_to_direction.insert('L', vec![Up, Right]);
+        char_to_direction.insert('J', vec![Up, Left]);
+        char_to_direction.insert('7', vec![Down, Left]);
+        char_to_direction.insert('F', vec![Down, Right]);
+
+        let mut direction_to_pipes: HashMap<Direction, HashSet<char>> = HashMap::new();
+        direction_to_pipes.insert(Up,    HashSet::from(['|', '7', 'F', 'S']));
+        direction_to_pipes.insert(Down,  HashSet::from(['|', 'J', 'L', 'S']));
+        direction_to_pipes.insert(Right, HashSet::from(['-', '7', 'J', 'S']));
+        direction_to_pipes.insert(Left,  HashSet::from(['-', 'F', 'L', 'S']));
+
+        if !map.get(start.into()).is_some_and(|c| char_to_direction.contains_key(c)) {
+            return Err(ParseError::new("the start must be on a pipe of the map", start_str));
+        }
+
+        let mut solution = Self {
+            map,
+            char_to_direction,
+            direction_to_pipes,
+            start: start.into(),
+            path: HashSet::new(),
+            farthest: 0,
+        };
+
+        // Both parts need the loop
+        let search = bfs([solution.start], |&position| solution.find_neighboors(position));
+        solution.farthest = *search.distances().values().max().unwrap();
+        solution.path = search.states().copied().collect();
+        Ok(solution)
+    }
+
+    fn part1(&mut self) -> Answer {
+        self.farthest.into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        self.inside().len().into()
+    }
+
+    fn render(&mut self) -> Option<Canvas> {
+        let mut canvas = Canvas::from_grid(&self.map, |&c| if c == '.' { Color::DARK } else { Color::GRAY });
+        canvas.overlay(self.path.iter().copied(), Color::YELLOW);
+        canvas.overlay(self.inside(), Color::GREEN);
+        Some(canvas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIMPLE_LOOP: &str = "\
+1,1
+-L|F7
+7F-7|
+L|7||
+-L-J|
+L|-JF";
+
+    const COMPLEX_LOOP: &str = "\
+0,2
+7-F7-
+.FJ|7
+FJLL7
+|F--J
+LJ.LJ";
+
+    const ENCLOSED: &str = "\
+1,1
+...........
+.F-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+    const ENCLOSED_LARGER: &str = "\
+12,4
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJF7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+    const ENCLOSED_JUNK: &str = "\
+4,0
+FF7F7F7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(SIMPLE_LOOP).unwrap().part1(), 4.into());
+        assert_eq!(Solution::from_input(COMPLEX_LOOP).unwrap().part1(), 8.into());
+    }
+
+    #[test]
+    fn part2() {
+        for (example, expected) in [(ENCLOSED, 4), (ENCLOSED_LARGER, 8), (ENCLOSED_JUNK, 10)] {
+            assert_eq!(Solution::from_input(example).unwrap().part2(), expected.into());
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("1,x\n.F7\n.LJ"), "x");
+        assert_eq!(error("0,0\n.F7\n.LJ"), "0,0");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+
+use crate::grid::Grid;
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    empty_rows: HashSet<usize>,
+    empty_cols: HashSet<usize>,
+    galaxies: Vec<Galaxy>,
+}
+
+impl Solution {
+    fn compute_result(&self, expansion_factor: usize) -> usize {
+        let mut galaxies = self.galaxies.clone();
+
+        // Expand the universe
+        for empty_col in &self.empty_cols {
+            for galaxy in &mut galaxies {
+                if galaxy.original_x > *empty_col {
+                    galaxy.x += expansion_factor;
+                }
+            }
+        }
+        
+        for empty_row in &self.empty_rows {
+            for galaxy in &mut galaxies {
+                if galaxy.original_y > *empty_row {
+                    galaxy.y += expansion_factor;
+                }
+            }
+        }
+
+        // Sum the distance between every pair
+        let mut res = 0;
+        for i in 0..galaxies.len(){
+            for j in i+1..galaxies.len(){
+                let galaxy_a = &galaxies[i];
+                let galaxy_b = &galaxies[j];
+                res += galaxy_a.x.abs_diff(galaxy_b.x) + galaxy_a.y.abs_diff(galaxy_b.y);
+            }
+        }
+        res
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let image = Grid::parse(input, Some)?;
+
+        let empty_rows = (0..image.height()).filter(|&y| image.row(y).iter().all(|&c| c != '#')).collect();
+        let empty_cols = (0..image.width()).filter(|&x| image.column(x).all(|&c| c != '#')).collect();
+        let galaxies = image.positions(|&c| c == '#').map(|(x, y)| Galaxy::new(x, y)).collect();
+
+        Ok(Self {
+            empty_rows,
+            empty_cols,
+            galaxies,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        self.compute_result(1).into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        self.compute_result(999_999).into()
+    }
+}
+
+
+#[derive(Debug, PartialEq, Eq)]
+enum Cell {
+    Galaxy,
+    Empty,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Galaxy {
+    original_x: usize,
+    original_y: usize,
+    x: usize,
+    y: usize,
+}
+
+impl Galaxy {
+    fn new(original_x: usize, original_y: usize) -> Self {
+        Self {
+            original_x,
+            original_y,
+            x: original_x,
+            y: original_y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::differential::assert_agree;
+    use crate::generate;
+
+    const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 374.into());
+    }
+
+    #[test]
+    fn expansion() {
+        let solution = Solution::from_input(EXAMPLE).unwrap();
+        assert_eq!(solution.compute_result(9), 1030);
+        assert_eq!(solution.compute_result(99), 8410);
+    }
+
+    /// Reference for the expansion offsets: copies the empty rows and columns for real, then adds up
+    /// the distances between the galaxies.
+    fn naive_distances(image: &str, expansion_factor: usize) -> usize {
+        let copies = |empty: bool| if empty { expansion_factor + 1 } else { 1 };
+        let rows = image.lines().map(|row| row.chars().collect_vec()).collect_vec();
+        let empty_cols = (0..rows[0].len()).map(|x| rows.iter().all(|row| row[x] != '#')).collect_vec();
+        let expanded = rows
+            .iter()
+            .flat_map(|row| {
+                let row = row.iter().zip(&empty_cols).flat_map(|(&c, &empty)| vec![c; copies(empty)]).collect_vec();
+                let count = copies(!row.contains(&'#'));
+                vec![row; count]
+            })
+            .collect_vec();
+
+        let galaxies = expanded
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().positions(|&c| c == '#').map(move |x| (x, y)))
+            .collect_vec();
+        galaxies.iter().tuple_combinations().map(|(a, b)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1)).sum()
+    }
+
+    #[test]
+    fn matches_naive() {
+        let images = (0..20)
+            .map(|seed| generate::image(&mut generate::rng(seed), 4 + seed as usize % 8, 10, 0.1))
+            .chain([EXAMPLE.to_string()]);
+        let inputs = images.cartesian_product([1, 2, 9]);
+        assert_agree(
+            inputs,
+            |(image, expansion_factor)| Solution::from_input(image).unwrap().compute_result(*expansion_factor),
+            |(image, expansion_factor)| naive_distances(image, *expansion_factor),
+        );
+    }
+}
\ No newline at end of file
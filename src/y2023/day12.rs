@@ -1,71 +1,16 @@
-use std::fs::read_to_string;
-use std::io::{self, Write};
-
 use itertools::Itertools;
 use std::collections::HashMap;
 
+use crate::error::{parse_number, split_once};
+use crate::input::fields;
+use crate::{Answer, ParseError, Solver};
+
 pub struct Solution {
     lines: Vec<String>,
     entries: Vec<Entry>,
 }
 
 impl Solution {
-    pub fn init() -> Self {
-        let mut lines = Vec::new();
-        for line in read_to_string("inputs/day12.txt").unwrap().lines() {
-            lines.push(line.to_string());
-        }
-
-        let mut entries = Vec::with_capacity(lines.len());
-        for line in &lines { 
-            let (map, pattern) = line.split_once(" ").unwrap();
-            let map = map.chars().map(|c| {
-                match c {
-                    '?' => Tile::Unknown,
-                    '.' => Tile::Operational,
-                    '#' => Tile::Damaged,
-                    _ => panic!("Invalid character in map"),
-                }
-            }).collect_vec();
-            let pattern = pattern.split(",").map(|s| s.parse::<usize>().unwrap()).collect_vec();
-            entries.push(Entry::new(map, pattern));            
-        }
-
-        Self {
-            lines,
-            entries,
-        }
-    }
-
-    fn part1(&mut self) -> usize {
-        let mut res = 0;
-
-        for entry in &self.entries {
-            res += self.solve_entry(&entry.map, &entry.pattern);
-        }
-
-        res
-    }
-
-    fn part2(&mut self) -> usize {
-        let mut res = 0;
-
-        for entry in &self.entries {
-            // Extend the input
-            // BEURK
-            let mut tmp = entry.map.clone();
-            tmp.push(Tile::Unknown);
-            let mut map = vec![tmp; 4].iter().flatten().map(|x| *x).collect_vec();
-            map.extend(entry.map.clone());
-            let pattern = vec![entry.pattern.clone(); 5].iter().flatten().map(|x| *x).collect_vec();
-            
-            res += self.solve_entry(&map, &pattern);
-        }
-
-        res
-
-    }
-
     fn solve_entry_rec(&self, map: &Vec<Tile>, pattern: &Vec<usize>, num_hash: usize, index: usize, pattern_index: usize, cache: &mut HashMap<(usize, usize, usize), usize>) -> usize {
         if let Some(value) = cache.get(&(num_hash, index, pattern_index)) {
             return *value;
@@ -115,24 +60,67 @@ impl Solution {
         let mut cache: HashMap<(usize, usize, usize), usize> = HashMap::new();
         self.solve_entry_rec(map, pattern, 0, 0, 0, &mut cache)
     }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 12;
+    const TITLE: &'static str = "Hot Springs";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+        for line in input.lines() {
+            lines.push(line.to_string());
+        }
+
+        let mut entries = Vec::with_capacity(lines.len());
+        for line in input.lines() {
+            let (map, pattern) = split_once(line, " ")?;
+            let map = map.char_indices().map(|(i, c)| {
+                match c {
+                    '?' => Ok(Tile::Unknown),
+                    '.' => Ok(Tile::Operational),
+                    '#' => Ok(Tile::Damaged),
+                    _ => Err(ParseError::new("expected `?`, `.` or `#`", &map[i..i + c.len_utf8()])),
+                }
+            }).collect::<Result<_, _>>()?;
+            let pattern = fields(pattern, ',').map(parse_number).collect::<Result<_, _>>()?;
+            entries.push(Entry::new(map, pattern));
+        }
+
+        Ok(Self {
+            lines,
+            entries,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        let mut res = 0;
+
+        for entry in &self.entries {
+            res += self.solve_entry(&entry.map, &entry.pattern);
+        }
+
+        res.into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        let mut res = 0;
+
+        for entry in &self.entries {
+            // Extend the input
+            // BEURK
+            let mut tmp = entry.map.clone();
+            tmp.push(Tile::Unknown);
+            let mut map = vec![tmp; 4].iter().flatten().map(|x| *x).collect_vec();
+            map.extend(entry.map.clone());
+            let pattern = vec![entry.pattern.clone(); 5].iter().flatten().map(|x| *x).collect_vec();
+            
+            res += self.solve_entry(&map, &pattern);
+        }
+
+        res.into()
 
-    pub fn solve(&mut self) {
-        println!("========= DAY 12 ========");
-        
-        print!("Solving part 1: ");
-        io::stdout().flush().unwrap();
-        let start = std::time::Instant::now();
-        let part1 = self.part1();
-        let part1_time = start.elapsed();
-        println!("{:?} (took {:?})", part1, part1_time);
-
-        print!("Solving part 2: ");
-        io::stdout().flush().unwrap();
-        let start = std::time::Instant::now();
-        let part2 = self.part2();
-        let part2_time = start.elapsed();
-        println!("{:?} (took {:?})", part2, part2_time);
-        println!();
     }
 }
 
@@ -156,4 +144,34 @@ impl Entry {
             pattern,
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 21.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 525152.into());
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("???.### 1,x"), "x");
+        assert_eq!(error("??a 1"), "a");
+    }
 }
\ No newline at end of file
@@ -1,62 +1,96 @@
-use std::fs::read_to_string;
-use std::io::{self, Write};
-
 use itertools::Itertools;
 
+use crate::grid::Grid;
+use crate::input::blocks;
+use crate::{Answer, ParseError, Solver};
+
 pub struct Solution {
-    patterns: Vec<Vec<Vec<char>>>,
-    patterns_transposed: Vec<Vec<Vec<char>>>,
+    patterns: Vec<Grid<char>>,
+    patterns_transposed: Vec<Grid<char>>,
 }
 
 impl Solution {
-    pub fn init() -> Self {
-        let content = read_to_string("inputs/day13.txt").unwrap();
-        let blocks = content.split("\n\n").collect_vec();
+    fn try_possibility(&self, lines: &Grid<char>, start: usize, end: usize) -> bool {
+        let mut i = start;
+        let mut j = end;
+        while i < j {
+            if lines.row(i) != lines.row(j) {
+                return false;
+            }
+            i += 1;
+            j -= 1;
+        }
+        i != j
+    }
+
+    fn analyze_rows(&self, lines: &Grid<char>, ignore: Option<isize>) -> usize {
+        let ignore = ignore.unwrap_or(-1);
+
+        // Start from the start 
+        let n = lines.height();
+        for possibility in (1..n).rev() {
+            if (possibility / 2 + 1) as isize != ignore && self.try_possibility(lines, 0, possibility) {
+                return possibility / 2 + 1;
+            }
+        }
+        
+        // Start from the end 
+        let n = lines.height();
+        for possibility in 0..n-1 {
+            if ((possibility+n-1) / 2 + 1) as isize != ignore && self.try_possibility(lines, possibility, n-1) {
+                return (possibility+n-1) / 2 + 1;
+            }
+        }
+
+        0
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 13;
+    const TITLE: &'static str = "Point of Incidence";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let content = input;
+        let blocks = blocks(content).collect_vec();
         let mut patterns = vec![];
         let mut patterns_transposed = vec![];
         for block in blocks {
-            let regular = block.lines().map(|line| line.chars().collect_vec()).collect_vec();
-            let mut transposed = vec![vec![]; regular[0].len()];
-
-            for (_i, line) in block.lines().map(|s| s.to_string()).collect_vec().iter().enumerate() {
-                for (j, c) in line.chars().enumerate() {
-                    transposed[j].push(c);
-                }
-            }
-            
+            let regular = Grid::parse(block, |c| matches!(c, '#' | '.').then_some(c))?;
+            patterns_transposed.push(regular.transpose());
             patterns.push(regular);
-            patterns_transposed.push(transposed);
         }
 
-        Self {
+        Ok(Self {
             patterns,
             patterns_transposed,
-        }
+        })
     }
 
-    fn part1(&mut self) -> usize{
+    fn part1(&mut self) -> Answer {
         let mut res = 0;
         for i in 0..self.patterns.len() {
             res += self.analyze_rows(&self.patterns[i], None) * 100 
                  + self.analyze_rows(&self.patterns_transposed[i], None)
         }
-        res
+        res.into()
     }
 
-    fn part2(&mut self) -> usize {
+    fn part2(&mut self) -> Answer {
         let mut res = 0;
         for i in 0..self.patterns.len() {
             let base = (self.analyze_rows(&self.patterns[i], None),self.analyze_rows(&self.patterns_transposed[i], None));
             let ignore_a = if base.0 != 0 { Some(base.0 as isize) } else { None };
             let ignore_b = if base.1 != 0 { Some(base.1 as isize) } else { None };
-            'main: for y in 0..self.patterns[i].len() {
-                for x in 0..self.patterns[i][0].len() {
-                    self.patterns[i][y][x] = match self.patterns[i][y][x] {
+            'main: for y in 0..self.patterns[i].height() {
+                for x in 0..self.patterns[i].width() {
+                    self.patterns[i][(x, y)] = match self.patterns[i][(x, y)] {
                         '#' => '.',
                         '.' => '#',
                         _ => panic!("Invalid character"),
                     };
-                    self.patterns_transposed[i][x][y] = match self.patterns_transposed[i][x][y] {
+                    self.patterns_transposed[i][(y, x)] = match self.patterns_transposed[i][(y, x)] {
                         '#' => '.',
                         '.' => '#',
                         _ => panic!("Invalid character"),
@@ -69,12 +103,12 @@ impl Solution {
                         break 'main;
                     }
 
-                    self.patterns[i][y][x] = match self.patterns[i][y][x] {
+                    self.patterns[i][(x, y)] = match self.patterns[i][(x, y)] {
                         '#' => '.',
                         '.' => '#',
                         _ => panic!("Invalid character"),
                     };
-                    self.patterns_transposed[i][x][y] = match self.patterns_transposed[i][x][y] {
+                    self.patterns_transposed[i][(y, x)] = match self.patterns_transposed[i][(y, x)] {
                         '#' => '.',
                         '.' => '#',
                         _ => panic!("Invalid character"),
@@ -82,61 +116,7 @@ impl Solution {
                 }
             }
         }
-        res
-    }
-
-    fn try_possibility(&self, lines: &Vec<Vec<char>>, start: usize, end: usize) -> bool {
-        let mut i = start;
-        let mut j = end;
-        while i < j {
-            if lines[i] != lines[j] {
-                return false;
-            }
-            i += 1;
-            j -= 1;
-        }
-        i != j
-    }
-
-    fn analyze_rows(&self, lines: &Vec<Vec<char>>, ignore: Option<isize>) -> usize {
-        let ignore = ignore.unwrap_or(-1);
-
-        // Start from the start 
-        let n = lines.len();
-        for possibility in (1..n).rev() {
-            if (possibility / 2 + 1) as isize != ignore && self.try_possibility(lines, 0, possibility) {
-                return possibility / 2 + 1;
-            }
-        }
-        
-        // Start from the end 
-        let n = lines.len();
-        for possibility in 0..n-1 {
-            if ((possibility+n-1) / 2 + 1) as isize != ignore && self.try_possibility(lines, possibility, n-1) {
-                return (possibility+n-1) / 2 + 1;
-            }
-        }
-
-        0
-    }
-
-    pub fn solve(&mut self) {
-        println!("========= DAY 13 ========");
-        
-        print!("Solving part 1: ");
-        io::stdout().flush().unwrap();
-        let start = std::time::Instant::now();
-        let part1 = self.part1();
-        let part1_time = start.elapsed();
-        println!("{:?} (took {:?})", part1, part1_time);
-
-        print!("Solving part 2: ");
-        io::stdout().flush().unwrap();
-        let start = std::time::Instant::now();
-        let part2 = self.part2();
-        let part2_time = start.elapsed();
-        println!("{:?} (took {:?})", part2, part2_time);
-        println!();
+        res.into()
     }
 }
 
@@ -144,4 +124,36 @@ impl Solution {
 enum Reflection {
     Vertical(usize),
     Horizontal(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 405.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 400.into());
+    }
 }
\ No newline at end of file
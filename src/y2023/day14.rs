@@ -0,0 +1,180 @@
+use crate::cycle;
+use crate::grid::Grid;
+use crate::render::{Canvas, Color};
+use crate::trace::{Event, Tracer};
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    map: Grid<char>,
+    tracer: Tracer,
+}
+
+impl Solution {
+    fn normalize(val: isize) -> isize {
+        match val {
+            val if val < 0 => -1,
+            val if val > 0 => 1,
+            _ => 0,
+        }
+    }
+
+    fn apply_gravity(&self, map: &mut Grid<char>, gravity: (isize, isize)) {
+        // for example, if gravity is (0, -1), we want to move all the rocks up
+        // so start from the top, and move down
+
+        let (gx, gy) = gravity;
+
+        let width = map.width() as isize;
+        let height = map.height() as isize;
+        
+        let (mut x, mut y, end_x, end_y) = match gravity {
+            (0, -1) => (0, 0, width, 0),
+            (0, 1) => (0, height-1, width, height-1),
+            (-1, 0) => (0, 0, 0, height),
+            (1, 0) => (width-1, 0, width-1, height),
+            _ => unreachable!("gravity can only be (0, -1), (0, 1), (-1, 0), or (1, 0)"),
+        };
+
+        let dx = Self::normalize(end_x - x);
+        let dy = Self::normalize(end_y - y);
+        let offset = if end_x == x {
+            width
+        } else {
+            height
+        };
+
+        while x != end_x || y != end_y {
+            // work on the given row/col
+            let mut placing_pos = (-1, -1);
+            for i in 0..offset {
+                let cell = map[((x - gx * i) as usize, (y - gy * i) as usize)];
+                if cell == 'O' {
+                    // Two possibilities, we can place it somewhere or not
+                    if placing_pos != (-1, -1) {
+                        // We can place it there, so move it
+                        map[((x - gx * i) as usize, (y - gy * i) as usize)] = '.';
+                        map[(placing_pos.0 as usize, placing_pos.1 as usize)] = 'O';
+
+                        // and set the placing pos to the next one
+                        placing_pos = (placing_pos.0 - gx, placing_pos.1 - gy);
+                    } 
+                }
+                else if cell == '#' {
+                    // It's a rock, so we need to reset the placing_pos
+                    placing_pos = (-1, -1);
+                } else if cell == '.' {
+                    // if the placing_pos is not set, set it, otherwise leave it
+                    if placing_pos == (-1, -1) {
+                        placing_pos = (x - gx * i, y - gy * i);
+                    }
+                } else {
+                    unreachable!("cell can only be 'O', '#', or '.'");
+                }
+            }
+            
+            x += dx;
+            y += dy;
+        }
+
+        self.tracer.emit(|| {
+            Event::new("tilt")
+                .with("gravity", vec![gx, gy])
+                .with("load", self.calculate_load(map))
+                .with("map", map.rows().map(|row| row.iter().collect::<String>()).collect::<Vec<_>>())
+        });
+    }
+
+    fn apply_cycle(&self, map: &mut Grid<char>) {
+        for gravity in [(0, -1), (-1, 0), (0, 1), (1, 0)] {
+            self.apply_gravity(map, gravity);
+        }
+    }
+
+    fn calculate_load(&self, map: &Grid<char>) -> usize {
+        map.positions(|&c| c == 'O').map(|(_, y)| map.height() - y).sum()
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            map: Grid::parse(input, |c| matches!(c, 'O' | '#' | '.').then_some(c))?,
+            tracer: Tracer::default(),
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        let mut map = self.map.clone();
+        self.apply_gravity(&mut map, (0, -1));
+        self.calculate_load(&map).into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        let target_cycles = 1000000000;
+
+        let history = cycle::hashed(self.map.clone(), |map| {
+            let mut map = map.clone();
+            self.apply_cycle(&mut map);
+            map
+        });
+        self.calculate_load(history.state_at(target_cycles)).into()
+    }
+
+    fn render(&mut self) -> Option<Canvas> {
+        let mut map = self.map.clone();
+        self.apply_gravity(&mut map, (0, -1));
+        Some(Canvas::from_grid(&map, |&c| match c {
+            'O' => Color::ORANGE,
+            '#' => Color::GRAY,
+            _ => Color::DARK,
+        }))
+    }
+
+    fn tracer(&mut self) -> Option<&mut Tracer> {
+        Some(&mut self.tracer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::{Recorder, Value};
+
+    const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 136.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 64.into());
+    }
+
+    #[test]
+    fn trace() {
+        let recorder = Recorder::default();
+        let mut solution = Solution::from_input(EXAMPLE).unwrap();
+        solution.tracer.attach(Box::new(recorder.clone()));
+        solution.part1();
+
+        let events = recorder.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].get("load"), Some(&Value::Number(136)));
+    }
+}
\ No newline at end of file
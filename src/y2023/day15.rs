@@ -0,0 +1,139 @@
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::error::{parse_number, split_once};
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    steps: Vec<String>,
+    operations: Vec<Operation>,
+}
+
+enum Operation {
+    Remove(String),
+    Insert(String, u32),
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(step: &str) -> Result<Self, Self::Err> {
+        if let Some(label) = step.strip_suffix('-') {
+            return Ok(Operation::Remove(label.to_string()));
+        }
+        let (label, focal_length) = split_once(step, "=")?;
+        Ok(Operation::Insert(label.to_string(), parse_number(focal_length)?))
+    }
+}
+
+impl Solution {
+    fn hash(&self, string: &str) -> u32 {
+        let mut current = 0;
+
+        for c in string.chars() {
+            let ascii = c as u32;
+            current = ((current + ascii) * 17) % 256;
+        }
+
+        current
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 15;
+    const TITLE: &'static str = "Lens Library";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let Some(line) = input.lines().next() else {
+            return Err(ParseError::new("expected a sequence of steps", input));
+        };
+
+        Ok(Self {
+            steps: line.split(",").map(str::to_string).collect(),
+            operations: line.split(",").map(str::parse).collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        let mut sum = 0;
+        for step in &self.steps {
+            sum += self.hash(step);
+        }
+        sum.into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        // Box elle a un label mais aussi un num entre 0 et 255 ? 
+        let mut boxes: Vec<Vec<(String, u32)>> = vec![vec![]; 256];
+
+        for operation in &self.operations {
+            match operation {
+                Operation::Remove(label) => {
+                    let hash = self.hash(label) as usize;
+
+                    boxes[hash] = boxes[hash].iter().cloned().filter(|(l, _focal)| l != label).collect_vec();
+                }
+                &Operation::Insert(ref label, focal_length) => {
+                    let hash = self.hash(label) as usize;
+                    let mut found = false;
+
+                    for (l, old_focal_length) in &mut boxes[hash] {
+                        if l == label {
+                            *old_focal_length = focal_length;
+                            found = true;
+                            break;
+                        }
+                    }
+                    if !found {
+                        boxes[hash].push((label.to_string(), focal_length));
+                    }
+                }
+            }
+        }
+
+        let mut res = 0;
+        for box_index in 0..256 {
+            for (slot_index, (_label, focal_length)) in boxes[box_index].iter().enumerate() {
+                res += (box_index + 1) * (slot_index + 1) * (*focal_length as usize);
+            }
+        }
+        res.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 1320.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 145.into());
+    }
+
+    #[test]
+    fn hash() {
+        let solution = Solution::from_input(EXAMPLE).unwrap();
+        assert_eq!(solution.hash("HASH"), 52);
+        assert_eq!(solution.hash("rn=1"), 30);
+        assert_eq!(solution.hash("rn"), 0);
+        assert_eq!(solution.hash("qp"), 1);
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error(""), "");
+        assert_eq!(error("rn=x,cm-"), "x");
+        assert_eq!(error("rn=1,cm"), "cm");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,163 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::render::{Canvas, Color};
+use crate::trace::{Event, Tracer, Value};
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    map: Grid<char>,
+    tracer: Tracer,
+}
+
+impl Solution {
+    fn update_bean(&self, beam: Beam, cache: &mut HashSet<Beam>) -> Vec<Beam> {
+        let cell = self.map[beam.position];
+        let beams = match cell {
+            '.' => vec![beam.towards(beam.direction)],
+            '\\' if beam.direction.is_horizontal() => vec![beam.towards(beam.direction.turn_right())],
+            '\\' => vec![beam.towards(beam.direction.turn_left())],
+            '/' if beam.direction.is_horizontal() => vec![beam.towards(beam.direction.turn_left())],
+            '/' => vec![beam.towards(beam.direction.turn_right())],
+            '|' if !beam.direction.is_horizontal() => vec![beam.towards(beam.direction)],
+            '-' if beam.direction.is_horizontal() => vec![beam.towards(beam.direction)],
+            '|' => vec![beam.towards(Direction::Down), beam.towards(Direction::Up)],
+            '-' => vec![beam.towards(Direction::Right), beam.towards(Direction::Left)],
+            _ => panic!("Unknown cell: {}", cell),
+        };
+        beams.into_iter().filter(|beam| self.map.contains(beam.position) && cache.insert(*beam)).collect_vec()
+    }
+
+    /// Tiles crossed by the light entering with `beam`.
+    fn energized(&self, beam: Beam) -> HashSet<Point> {
+        let mut cache = HashSet::from([beam]);
+        let mut beams = vec![beam];
+
+        while !beams.is_empty() {
+            self.tracer.emit(|| Event::new("beams").with("beams", beams.clone()));
+            let mut new_beams = vec![];
+            for beam in beams {
+                new_beams.append(&mut self.update_bean(beam, &mut cache));
+            }
+            beams = new_beams;
+        }
+
+        cache.into_iter().map(|beam| beam.position).collect()
+    }
+
+    fn number_energized_from_start(&self, beam: Beam) -> usize {
+        self.energized(beam).len()
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            map: Grid::parse(input, |c| matches!(c, '.' | '\\' | '/' | '|' | '-').then_some(c))?,
+            tracer: Tracer::default(),
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        self.number_energized_from_start(Beam::new(0, 0, Direction::Right)).into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        let mut best = 0;
+        let (width, height) = (self.map.width() as isize, self.map.height() as isize);
+
+        for x in 0..width {
+            best = best.max(self.number_energized_from_start(Beam::new(x, 0, Direction::Down)));
+            best = best.max(self.number_energized_from_start(Beam::new(x, height-1, Direction::Up)));
+        }
+
+        for y in 0..height {
+            best = best.max(self.number_energized_from_start(Beam::new(0, y, Direction::Right)));
+            best = best.max(self.number_energized_from_start(Beam::new(width-1, y, Direction::Left)));
+        }
+
+        best.into()
+    }
+
+    fn render(&mut self) -> Option<Canvas> {
+        let mut canvas = Canvas::from_grid(&self.map, |&c| if c == '.' { Color::DARK } else { Color::GRAY });
+        canvas.overlay(self.energized(Beam::new(0, 0, Direction::Right)), Color::YELLOW);
+        canvas.overlay(self.map.positions(|&c| c != '.').map(Point::from), Color::WHITE);
+        Some(canvas)
+    }
+
+    fn tracer(&mut self) -> Option<&mut Tracer> {
+        Some(&mut self.tracer)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Beam {
+    position: Point,
+    direction: Direction,
+}
+
+impl Beam {
+    fn new(x: isize, y: isize, direction: Direction) -> Self {
+        Self {
+            position: Point::new(x, y),
+            direction,
+        }
+    }
+
+    /// One step further, heading in `direction`.
+    fn towards(&self, direction: Direction) -> Self {
+        Self {
+            position: self.position + direction,
+            direction,
+        }
+    }
+}
+
+/// `[[x, y], direction]`
+impl From<Beam> for Value {
+    fn from(beam: Beam) -> Self {
+        Value::List(vec![beam.position.into(), beam.direction.into()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 46.into());
+    }
+
+    #[test]
+    fn entry_tile() {
+        // The entry tile counts even when the light never comes back to it
+        let solution = Solution::from_input("..\n..").unwrap();
+        assert_eq!(solution.number_energized_from_start(Beam::new(0, 0, Direction::Right)), 2);
+        let solution = Solution::from_input("/.\n..").unwrap();
+        assert_eq!(solution.number_energized_from_start(Beam::new(0, 0, Direction::Right)), 1);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 51.into());
+    }
+}
\ No newline at end of file
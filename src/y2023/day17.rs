@@ -0,0 +1,118 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::render::{Canvas, Color};
+use crate::search::{dijkstra, Search};
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    map: Grid<isize>,
+}
+
+impl Solution {
+    /// Ways from the top left to the bottom right corner, going straight for `min` to `max` blocks
+    /// before each turn.
+    fn search(&self, min: isize, max: isize) -> Search<(Point, Direction), isize> {
+        let goal = Point::new(self.map.width() as isize - 1, self.map.height() as isize - 1);
+
+        // Each state is where a crucible stopped, and the direction it came in, it must turn next
+        let starts = [(Point::ORIGIN, Direction::Right), (Point::ORIGIN, Direction::Down)];
+        dijkstra(starts, |&(current, direction)| {
+            // Moves are yielded without collecting them, as this runs for every state
+            [direction.turn_left(), direction.turn_right()].into_iter().flat_map(move |neighbor_direction| {
+                (1..=max)
+                    .map_while(move |d| {
+                        let neighbor = current + neighbor_direction.delta() * d;
+                        self.map.get(neighbor).map(|&heat_loss| (neighbor, heat_loss)) // Stop when it goes out of the map
+                    })
+                    .scan(0, move |cost, (neighbor, heat_loss)| {
+                        *cost += heat_loss;
+                        Some(((neighbor, neighbor_direction), *cost))
+                    })
+                    .skip(min as usize - 1)
+            })
+        }, |&(current, _)| current == goal)
+    }
+
+    /// Least heat loss from the top left to the bottom right corner.
+    fn least_heat_loss(&self, min: isize, max: isize) -> isize {
+        let search = self.search(min, max);
+        search.goal().and_then(|goal| search.distance(goal)).unwrap()
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 17;
+    const TITLE: &'static str = "Clumsy Crucible";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            map: Grid::parse(input, |c| Some(c.to_digit(10)? as isize))?,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        self.least_heat_loss(1, 3).into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        self.least_heat_loss(4, 10).into()
+    }
+
+    fn render(&mut self) -> Option<Canvas> {
+        let mut canvas = Canvas::from_grid(&self.map, |&heat_loss| Color::gray(heat_loss as f64 / 10.));
+
+        // The states are where the crucible stops, fill in the blocks it goes through in between
+        let search = self.search(1, 3);
+        let path = search.path(search.goal()?)?;
+        let mut blocks = vec![Point::ORIGIN];
+        for &(block, direction) in &path[1..] {
+            let mut current = *blocks.last().unwrap();
+            while current != block {
+                current += direction;
+                blocks.push(current);
+            }
+        }
+
+        canvas.overlay(blocks, Color::RED);
+        Some(canvas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    const UNFORTUNATE: &str = "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 102.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 94.into());
+        assert_eq!(Solution::from_input(UNFORTUNATE).unwrap().part2(), 71.into());
+    }
+}
\ No newline at end of file
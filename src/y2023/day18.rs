@@ -0,0 +1,235 @@
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::error::{expect_prefix, parse_number};
+use crate::geometry::{Direction, Point};
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    lines: Vec<String>,
+    instructions: Vec<Instruction>,
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 18;
+    const TITLE: &'static str = "Lavaduct Lagoon";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+        for line in input.lines() {
+            lines.push(line.to_string());
+        }
+
+        Ok(Self {
+            instructions: input.lines().map(|line| line.parse()).collect::<Result<_, _>>()?,
+            lines,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        // Define variables
+        let mut inside_area = 0;
+        let mut side_area = 0;
+
+        // Using Green's theorem
+        let mut x = 0;
+        for instruction in &self.instructions {
+            let Point { x: dx, y: dy } = instruction.direction.delta();
+            let n = instruction.distance;
+            
+            inside_area += n * x * dy + dx * dy * n * (n + 1) / 2;
+            side_area += instruction.distance;
+
+            x += dx * n;
+        }
+
+        // Counter-clockwise plans enclose a negative area
+        (inside_area.abs() + side_area / 2 + 1).into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        // Define variables
+        let mut inside_area = 0;
+        let mut side_area = 0;
+
+        // Using Green's theorem
+        let mut x = 0;
+        for instruction in &self.instructions {
+            let Point { x: dx, y: dy } = instruction.hex_direction.delta();
+            let n = instruction.hex_distance;
+            
+            inside_area += n * x * dy + dx * dy * n * (n + 1) / 2;
+            side_area += instruction.hex_distance;
+
+            x += dx * n;
+        }
+
+        // Counter-clockwise plans enclose a negative area
+        (inside_area.abs() + side_area / 2 + 1).into()
+    }
+}
+
+#[derive(Debug)]
+struct Instruction {
+    direction: Direction,
+    distance: isize,
+    hex_distance: isize,
+    hex_direction: Direction,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    // Format is R/L/D/U space number space useless
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let split = line.split_whitespace();
+        let (direction_str, distance_str, rest_str) = split
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new("expected `<direction> <distance> (#<color>)`", line))?;
+        let direction = direction_str.parse::<Direction>()?;
+        let distance = parse_number::<isize>(distance_str)?;
+
+        let rest = expect_prefix(rest_str, "(#")?
+            .strip_suffix(")")
+            .filter(|rest| rest.len() == 6 && rest.is_ascii())
+            .ok_or_else(|| ParseError::new("expected `(#<6 hex digits>)`", rest_str))?;
+        let n = rest.len();
+        let hex_direction = match &rest[n-1..n] {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            _ => return Err(ParseError::new("invalid direction", &rest[n-1..n])),
+        };
+        let hex_distance = isize::from_str_radix(&rest[..n-1], 16)
+            .map_err(|_| ParseError::new("invalid hexadecimal number", &rest[..n-1]))?;
+
+        Ok(Self {
+            direction,
+            distance,
+            hex_distance,
+            hex_direction,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::differential::assert_agree;
+    use crate::generate;
+    use crate::search::bfs;
+
+    const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 62.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 952408144115i64.into());
+    }
+
+    /// Reference for Green's theorem: digs the trench cube by cube, then counts what is left once
+    /// the outside is flooded.
+    fn naive_lagoon(plan: &str) -> Answer {
+        let mut position = Point::ORIGIN;
+        let mut trench = HashSet::from([position]);
+        for line in plan.lines() {
+            let (direction, distance, _) = line.split(' ').collect_tuple().unwrap();
+            for _ in 0..distance.parse::<usize>().unwrap() {
+                position += direction.parse::<Direction>().unwrap();
+                trench.insert(position);
+            }
+        }
+
+        // Leave room around the trench for the water to go all around it
+        let (min_x, max_x) = trench.iter().map(|point| point.x).minmax().into_option().unwrap();
+        let (min_y, max_y) = trench.iter().map(|point| point.y).minmax().into_option().unwrap();
+        let (min, max) = (Point::new(min_x - 1, min_y - 1), Point::new(max_x + 1, max_y + 1));
+        let outside = bfs([min], |point| {
+            point.neighbors4().filter(|next| {
+                (min.x..=max.x).contains(&next.x) && (min.y..=max.y).contains(&next.y) && !trench.contains(next)
+            })
+        });
+
+        ((max.x - min.x + 1) * (max.y - min.y + 1) - outside.states().count() as isize).into()
+    }
+
+    /// Colors giving the same plan as the directions, for part 2 to be checked on small lagoons too.
+    fn with_matching_colors(plan: &str) -> String {
+        plan.lines()
+            .map(|line| {
+                let (direction, distance, _) = line.split(' ').collect_tuple().unwrap();
+                let color = format!("{:05x}{}", distance.parse::<usize>().unwrap(), "RDLU".find(direction).unwrap());
+                format!("{} {} (#{})", direction, distance, color)
+            })
+            .join("\n")
+    }
+
+    /// The same trench dug the other way round.
+    fn reversed(plan: &str) -> String {
+        plan.lines()
+            .rev()
+            .map(|line| {
+                let (direction, rest) = line.split_once(' ').unwrap();
+                format!("{} {}", &"DULR"["UDRL".find(direction).unwrap()..][..1], rest)
+            })
+            .join("\n")
+    }
+
+    #[test]
+    fn counter_clockwise() {
+        let mut solution = Solution::from_input("D 2 (#000021)\nR 2 (#000020)\nU 2 (#000023)\nL 2 (#000022)").unwrap();
+        assert_eq!(solution.part1(), 9.into());
+        assert_eq!(solution.part2(), 9.into());
+    }
+
+    #[test]
+    fn matches_naive() {
+        let plans = (0..30)
+            .map(|seed| generate::dig_plan(&mut generate::rng(seed), 1 + seed as usize % 6))
+            .chain([EXAMPLE.to_string()])
+            .flat_map(|plan| [reversed(&plan), plan])
+            .map(|plan| with_matching_colors(&plan));
+        assert_agree(
+            plans,
+            |plan| {
+                let mut solution = Solution::from_input(plan).unwrap();
+                (solution.part1(), solution.part2())
+            },
+            |plan| (naive_lagoon(plan), naive_lagoon(plan)),
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("R 6"), "R 6");
+        assert_eq!(error("X 6 (#70c710)"), "X");
+        assert_eq!(error("R x (#70c710)"), "x");
+        assert_eq!(error("R 6 70c710"), "70");
+        assert_eq!(error("R 6 (#70c71)"), "(#70c71)");
+        assert_eq!(error("R 6 (#70c714)"), "4");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,342 @@
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+
+use crate::error::{expect_prefix, parse_number, split_once};
+use crate::input::blocks;
+use crate::interval::{Interval, IntervalSet};
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part>,
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 19;
+    const TITLE: &'static str = "Aplenty";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let [workflows_str, parts_str] = blocks(input).collect::<Vec<_>>()[..] else {
+            return Err(ParseError::new("expected workflows and parts separated by a blank line", input));
+        };
+
+        // Parse the workflows
+        let mut workflows = HashMap::new();
+        for workflow in workflows_str.lines() {
+            let (name, rules_str) = split_once(workflow, "{")?;
+            let rules_str = rules_str
+                .strip_suffix("}")
+                .ok_or_else(|| ParseError::new("expected `}`", &rules_str[rules_str.len()..]))?;
+            let rules: Vec<Rule> = rules_str
+                .split(",")
+                .map(|rule_str| rule_str.parse())
+                .collect::<Result<_, _>>()?;
+            // Every part must leave the workflow somewhere
+            if !rules.last().is_some_and(|rule| matches!(rule.condition, Condition::AlwaysTrue)) {
+                let last = rules_str.rsplit(",").next().unwrap_or(rules_str);
+                return Err(ParseError::new("expected the last rule to have no condition", last));
+            }
+            workflows.insert(name.to_string(), Workflow::new(rules));
+        }
+
+        // Parse the parts
+        let parts = parts_str.lines().map(
+            |part_str| part_str.parse()
+        ).collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            workflows,
+            parts,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        let mut res = 0;
+        for part in &self.parts {
+            let mut position = "in".to_string();
+            while position != "R" && position != "A" {
+                let workflow = &self.workflows[&position];
+                position = workflow.apply(part);
+            }
+
+            if position == "A" {
+                res += part.x + part.a + part.m + part.s;
+            }
+        }
+
+        res.into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        let ratings = IntervalSet::from(Interval::inclusive(1, 4000));
+        let mut parts = VecDeque::new();
+        parts.push_front(("in".to_string(), PartInterval {
+            x: ratings.clone(),
+            m: ratings.clone(),
+            a: ratings.clone(),
+            s: ratings,
+        }));
+
+        let mut accepted_parts = vec![];
+
+        while let Some((position, part)) = parts.pop_front() {
+            let workflow = &self.workflows[&position];
+            
+            for (new_position, new_part) in workflow.apply_interval(&part) {
+                if new_position == "A" {
+                    accepted_parts.push(new_part);
+                } else if new_position != "R" {
+                    parts.push_back((new_position, new_part));
+                }
+            }
+        }
+
+        // Each part goes one way only, so the accepted ones don't overlap
+        accepted_parts.iter().map(PartInterval::count).sum::<u64>().into()
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    condition: Condition,
+    result: String,
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if let Some((start, result)) = value.split_once(":") {
+            let label = parse_label(start)?;
+            let value = parse_number::<usize>(start.get(2..).unwrap_or(""))?;
+            let condition = match start.get(1..2) {
+                Some(">") => Condition::GreaterThan(label, value),
+                Some("<") => Condition::LessThan(label, value),
+                _ => return Err(ParseError::new("expected `<` or `>`", start.get(1..).unwrap_or(start))),
+            };
+
+            Ok(Self {
+                condition,
+                result: result.to_string(),
+            })
+        } else {
+            Ok(Self {
+                condition: Condition::AlwaysTrue,
+                result: value.to_string(),
+            })
+        }
+    }
+}
+
+/// Reads the rating category at the start of `text`.
+fn parse_label(text: &str) -> Result<char, ParseError> {
+    match text.get(..1) {
+        Some(label @ ("x" | "m" | "a" | "s")) => Ok(label.chars().next().unwrap()),
+        _ => Err(ParseError::new("expected one of `x`, `m`, `a`, `s`", text.get(..1).unwrap_or(text))),
+    }
+}
+
+#[derive(Debug)]
+enum Condition {
+    GreaterThan(char, usize),
+    LessThan(char, usize),
+    AlwaysTrue,
+}
+
+#[derive(Debug)]
+struct Workflow {
+    rules: Vec<Rule>,
+}
+
+impl Workflow {
+    fn new(rules: Vec<Rule>) -> Self {
+        Self {
+            rules,
+        }
+    }
+    
+    fn apply(&self, part: &Part) -> String {
+        for rule in &self.rules {
+            // Check if the rule applyes
+            match rule.condition {
+                Condition::AlwaysTrue => return rule.result.clone(),
+                Condition::GreaterThan(label, value) => {
+                    if part.get(label) > value {
+                        return rule.result.clone();
+                    }
+                },
+                Condition::LessThan(label, value) => {
+                    if part.get(label) < value {
+                        return rule.result.clone();
+                    }
+                },
+            }
+        }
+
+        unreachable!("Error when applying workflow {:?} on part {:?}", self, part);
+    }
+
+    fn apply_interval(&self, part: &PartInterval) -> Vec<(String, PartInterval)> {
+        let mut res = vec![];
+        let mut part = part.clone();
+
+        for rule in &self.rules {
+            // Split the ratings between the ones matching the rule, and the ones going on to the next rules
+            let (label, matching, rest) = match rule.condition {
+                Condition::AlwaysTrue => {
+                    res.push((rule.result.clone(), part));
+                    break;
+                },
+                Condition::GreaterThan(label, value) => {
+                    let (below, above) = part.get(label).split_at(value as i64 + 1);
+                    (label, above, below)
+                },
+                Condition::LessThan(label, value) => {
+                    let (below, above) = part.get(label).split_at(value as i64);
+                    (label, below, above)
+                },
+            };
+
+            if !matching.is_empty() {
+                let mut part_split = part.clone();
+                part_split.set(label, matching);
+                res.push((rule.result.clone(), part_split));
+            }
+            if rest.is_empty() {
+                break;
+            }
+            part.set(label, rest);
+        }
+
+        res
+    }
+}
+
+#[derive(Debug, Clone)] 
+struct PartInterval {
+    x: IntervalSet,
+    m: IntervalSet,
+    a: IntervalSet,
+    s: IntervalSet,
+}
+
+impl PartInterval {
+    fn set(&mut self, label: char, value: IntervalSet) {
+        match label {
+            'x' => self.x = value,
+            'm' => self.m = value,
+            'a' => self.a = value,
+            's' => self.s = value,
+            _ => unreachable!("Invalid label"),
+        }
+    }
+
+    fn get(&self, label: char) -> &IntervalSet {
+        match label {
+            'x' => &self.x,
+            'm' => &self.m,
+            'a' => &self.a,
+            's' => &self.s,
+            _ => unreachable!("Invalid label"),
+        }
+    }
+
+    /// Number of distinct parts.
+    fn count(&self) -> u64 {
+        self.x.len() * self.m.len() * self.a.len() * self.s.len()
+    }
+}
+
+#[derive(Debug)]
+struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl FromStr for Part {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut res = Self {
+            x: 0,
+            m: 0,
+            a: 0,
+            s: 0,
+        };
+
+        let ratings = expect_prefix(value, "{")?
+            .strip_suffix("}")
+            .ok_or_else(|| ParseError::new("expected `}`", &value[value.len()..]))?;
+        for part in ratings.split(",") {
+            let (label, value) = split_once(part, "=")?;
+            res.set(parse_label(label)?, parse_number::<usize>(value)?);
+        }
+
+        Ok(res)
+    }
+}
+
+impl Part {
+    fn set(&mut self, label: char, value: usize) {
+        match label {
+            'x' => self.x = value,
+            'm' => self.m = value,
+            'a' => self.a = value,
+            's' => self.s = value,
+            _ => unreachable!("Invalid label"),
+        }
+    }
+
+    fn get(&self, label: char) -> usize {
+        match label {
+            'x' => self.x,
+            'm' => self.m,
+            'a' => self.a,
+            's' => self.s,
+            _ => unreachable!("Invalid label"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 19114.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 167409079868000i64.into());
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("in{a<2006:qkq}\n\n{x=1,m=2,a=3,s=4}"), "a<2006:qkq");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,154 @@
+use std::str::FromStr;
+
+use crate::error::{expect_prefix, parse_number, split_once};
+use crate::input::fields;
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    lines: Vec<String>,
+    games: Vec<Game>,
+}
+
+#[derive(Debug)]
+pub struct Draw {
+    pub r: u32,
+    pub g: u32,
+    pub b: u32,
+}
+
+impl Draw {
+    pub fn new(r: u32, g: u32, b: u32) -> Self {
+        Self {
+            r, g, b
+        }
+    }
+}
+
+impl FromStr for Draw {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut r = 0;
+        let mut g = 0;
+        let mut b = 0;
+
+        for s in fields(value, ',') {
+            let (digit, str_identifier) = split_once(s, " ")?;
+            let digit = parse_number::<u32>(digit)?;
+            match str_identifier {
+                "blue" => b = digit,
+                "red" => r = digit,
+                "green" => g = digit,
+                _ => return Err(ParseError::new("unknown color", str_identifier)),
+            };
+        }
+
+        Ok(Self::new(r, g, b))
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Draw>,
+}
+
+impl Game {
+    pub fn new(id: u32, draws: Vec<Draw>) -> Self {
+        Self {
+            id,
+            draws
+        }
+    }
+
+    pub fn can_be_played(&self, available_r: u32, available_g: u32, available_b: u32) -> bool {
+        self.draws.iter().all(|draw| draw.r <= available_r && draw.g <= available_g && draw.b <= available_b)
+    }
+
+    pub fn power(&self) -> u32 {
+        let required_r = self.draws.iter().max_by_key(|draw| draw.r).unwrap().r;
+        let required_g = self.draws.iter().max_by_key(|draw| draw.g).unwrap().g;
+        let required_b = self.draws.iter().max_by_key(|draw| draw.b).unwrap().b;
+
+        required_r * required_b * required_g
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (identifier, draws) = split_once(expect_prefix(value, "Game ")?, ":")?;
+        let identifier: u32 = parse_number(identifier)?;
+        let draws = fields(draws, ';').map(|draw_str| draw_str.parse()).collect::<Result<_, _>>()?;
+
+        Ok(Self::new(identifier, draws))
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+        for line in input.lines() {
+            lines.push(line.to_string());
+        }
+
+        Ok(Self {
+            games: input.lines().map(|line| line.parse()).collect::<Result<_, _>>()?,
+            lines,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        self
+            .games
+            .iter()
+            .filter(|game| game.can_be_played(12, 13, 14))
+            .map(|game| game.id)
+            .sum::<u32>().into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        self
+            .games
+            .iter()
+            .map(|game| game.power())
+            .sum::<u32>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 8.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 2286.into());
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("Game x: 3 blue"), "x");
+        assert_eq!(error("Game 1: 3 purple"), "purple");
+        assert_eq!(error("Gme 1: 3 blue"), "Gme 1");
+        assert_eq!(error("Game 1 3 blue"), "1 3 blue");
+        assert_eq!(error("Game 1: blue"), "blue");
+    }
+}
\ No newline at end of file
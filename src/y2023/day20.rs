@@ -0,0 +1,376 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crate::cycle;
+use crate::error::split_once;
+use crate::input::fields;
+use crate::trace::{Event, Tracer};
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    lines: Vec<String>,
+    modules: HashMap<String, Box<dyn Module>>,
+    tracer: Tracer,
+}
+
+impl Solution {
+    fn push_button(&self, modules: &mut HashMap<String, Box<dyn Module>>, low_pulses: &mut usize, high_pulses: &mut usize) {
+        let mut signals: VecDeque<(String, String, bool)> = VecDeque::new();
+        signals.push_back(("button".to_string(), "broadcaster".to_string(), false));
+        self.tracer.emit(|| Event::new("press"));
+
+        while let Some((origin, destination, pulse)) = signals.pop_front() {
+            self.tracer.emit(|| Event::new("pulse").with("from", origin.as_str()).with("to", destination.as_str()).with("high", pulse));
+
+            if pulse {
+                *high_pulses += 1;
+            } else {
+                *low_pulses += 1;
+            }
+
+            if let Some(module) = modules.get_mut(&destination) {
+                let next_signals = module.process_signal(origin.clone(), pulse);
+                signals.extend(next_signals)
+            }
+        }
+    }
+
+    /// Modules reachable from `start`, stopping at the one sending to rx.
+    fn circuit(&self, start: &str) -> Circuit {
+        let mut modules = BTreeMap::new();
+        let mut queue = vec![start.to_string()];
+        while let Some(label) = queue.pop() {
+            let Some(module) = self.modules.get(&label) else { continue };
+            if modules.contains_key(&label) || module.destinations().iter().any(|destination| destination == "rx") {
+                continue;
+            }
+            queue.extend(module.destinations().iter().cloned());
+            modules.insert(label, module.clone());
+        }
+
+        Circuit { start: start.to_string(), modules }
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 20;
+    const TITLE: &'static str = "Pulse Propagation";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+        let mut modules = HashMap::new();
+
+        for line in input.lines() {
+            // Parse module
+            let module = parse_module(line)?;
+            modules.insert(module.label().to_string(), module);
+            
+            lines.push(line.to_string());
+        }
+
+        if !modules.contains_key("broadcaster") {
+            return Err(ParseError::new("expected a `broadcaster` module", input));
+        }
+
+        // Set the last_pulses to false
+        let origins = modules.keys().map(|s| s.clone()).collect::<Vec<_>>();
+        for origin in origins {
+            let destinations = modules.get(&origin).unwrap().destinations().clone();
+            for destination in destinations {
+                if let Some(module) = modules.get_mut(&destination) {
+                    module.attach_input(origin.clone());
+                }
+            }
+        }
+
+        Ok(Self {
+            lines,
+            modules,
+            tracer: Tracer::default(),
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        let mut modules = self.modules.clone();
+
+        let mut low_pulses = 0;
+        let mut high_pulses = 0;
+
+        // Press button once
+        for _press in 0..1000 {
+            self.push_button(&mut modules, &mut low_pulses, &mut high_pulses);
+        }
+
+        (low_pulses * high_pulses).into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        // Each output of the broadcaster drives a separate counter, rx gets a low pulse once they all
+        // send a high pulse on the same press
+        if !self.modules.values().any(|module| module.destinations().iter().any(|destination| destination == "rx")) {
+            return Answer::Skipped("no module sends to rx".to_string());
+        }
+
+        let broadcaster = &self.modules["broadcaster"];
+        match broadcaster.destinations().iter().map(|start| self.circuit(start).period()).collect::<Result<Vec<_>, _>>() {
+            Ok(periods) => periods.into_iter().fold(1, num::integer::lcm).into(),
+            Err(reason) => Answer::Skipped(reason),
+        }
+    }
+
+    fn tracer(&mut self) -> Option<&mut Tracer> {
+        Some(&mut self.tracer)
+    }
+}
+
+/// Modules reached from one output of the broadcaster, up to the conjunction leading to rx.
+struct Circuit {
+    start: String,
+    modules: BTreeMap<String, Box<dyn Module>>,
+}
+
+impl Circuit {
+    /// Presses the button once, returning whether a high pulse left the circuit towards rx.
+    fn press(&mut self) -> bool {
+        let mut high_out = false;
+        let mut signals = VecDeque::from([("broadcaster".to_string(), self.start.clone(), false)]);
+        while let Some((origin, destination, pulse)) = signals.pop_front() {
+            match self.modules.get_mut(&destination) {
+                Some(module) => signals.extend(module.process_signal(origin, pulse)),
+                None => high_out |= pulse,
+            }
+        }
+        high_out
+    }
+
+    fn memory(&self) -> Vec<bool> {
+        self.modules.values().flat_map(|module| module.memory()).collect()
+    }
+
+    /// Number of presses between two high pulses towards rx, checking that the circuit sends them
+    /// on every multiple of the length of the cycle of its states and only then: otherwise the
+    /// counters would not all send theirs after the least common multiple of their periods.
+    fn period(mut self) -> Result<usize, String> {
+        // The states are the memories of the modules, the circuit itself is pressed in place as
+        // `hashed` only ever steps the last state
+        let mut presses = 0;
+        let mut high_presses = vec![];
+        let history = cycle::hashed(self.memory(), |_| {
+            presses += 1;
+            if self.press() {
+                high_presses.push(presses);
+            }
+            self.memory()
+        });
+
+        // The presses from the states of the cycle repeat, those before it were checked once
+        let cycle = history.cycle();
+        let multiples = (1..=(cycle.prefix + cycle.length) / cycle.length).map(|n| n * cycle.length).collect::<Vec<_>>();
+        if high_presses != multiples {
+            return Err(format!(
+                "the counter from {} sends high pulses on presses {:?}, with states repeating every {} presses after {}",
+                self.start, high_presses, cycle.length, cycle.prefix,
+            ));
+        }
+        Ok(cycle.length)
+    }
+}
+
+trait Module: ModuleClone {
+    fn label(&self) -> &str;
+    fn destinations(&self) -> &Vec<String>;
+
+    fn process_signal(&mut self, origin: String, pulse: bool) -> Vec<(String, String, bool)>;
+
+    fn attach_input(&mut self, _origin: String) {}
+
+    /// What the module remembers of the previous pulses.
+    fn memory(&self) -> Vec<bool> {
+        vec![]
+    }
+}
+
+trait ModuleClone {
+    fn clone_box(&self) -> Box<dyn Module>;
+}
+
+impl<T> ModuleClone for T
+where
+    T: 'static + Module + Clone,
+{
+    fn clone_box(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Module> {
+    fn clone(&self) -> Box<dyn Module> {
+        self.clone_box()
+    }
+}
+
+fn parse_module(line: &str) -> Result<Box<dyn Module>, ParseError> {
+    let (start, end) = split_once(line, " -> ")?;
+    let destinations = fields(end, ',').map(|s| s.to_string()).collect::<Vec<_>>();
+
+    if start.starts_with("%") { // Flip flop
+        let label = start[1..].to_string();
+        let destinations = destinations;
+        let memory = false;
+        Ok(Box::new(FlipFlop{ label, destinations, memory }))
+    } else if start.starts_with("&") { // Conjunction
+        let label = start[1..].to_string();
+        let destinations = destinations;
+        let received_pulses = BTreeMap::new();
+        Ok(Box::new(Conjunction{ label, destinations, received_pulses }))
+    } else if start == "broadcaster" { // Broadcaster
+        let destinations = destinations;
+        Ok(Box::new(Broadcaster{ destinations }))
+    } else {
+        Err(ParseError::new("expected `%`, `&` or `broadcaster`", start))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Broadcaster {
+    destinations: Vec<String>,
+}
+
+impl Module for Broadcaster {
+    fn label(&self) -> &str {
+        "broadcaster"
+    }
+
+    fn destinations(&self) -> &Vec<String> {
+        &self.destinations
+    }
+
+    fn process_signal(&mut self, _origin: String, _pulse: bool) -> Vec<(String, String, bool)> {
+        self.destinations.iter().map(|destination| ("broadcaster".to_string(), destination.clone(), false)).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Conjunction {
+    label: String,
+    destinations: Vec<String>,
+    received_pulses: BTreeMap<String, bool>,
+}
+
+impl Module for Conjunction {
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn destinations(&self) -> &Vec<String> {
+        &self.destinations
+    }
+
+    fn process_signal(&mut self, origin: String, pulse: bool) -> Vec<(String, String, bool)> {
+        if let Some(received_pulse) = self.received_pulses.get_mut(&origin) {
+            *received_pulse = pulse;
+            let output_pulse = !self.received_pulses.values().all(|&x| x);
+            self.destinations.iter().map(|destination| (self.label.clone(), destination.clone(), output_pulse)).collect()
+
+        } else {
+            panic!("Normal ça ? {} -{}> {}", origin, pulse, self.label);
+        }
+    }
+
+    fn attach_input(&mut self, origin: String) {
+        self.received_pulses.insert(origin, false);
+    }
+
+    fn memory(&self) -> Vec<bool> {
+        self.received_pulses.values().copied().collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FlipFlop {
+    label: String,
+    destinations: Vec<String>,
+    memory: bool,
+}
+
+impl Module for FlipFlop {
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn destinations(&self) -> &Vec<String> {
+        &self.destinations
+    }
+
+    fn process_signal(&mut self, _origin: String, pulse: bool) -> Vec<(String, String, bool)> {
+        if !pulse {
+            self.memory = !self.memory;
+            self.destinations.iter().map(|destination| (self.label.clone(), destination.clone(), self.memory)).collect()
+        } else {
+            vec![]
+        }
+    }
+
+    fn memory(&self) -> Vec<bool> {
+        vec![self.memory]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+    const EXAMPLE_2: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE_1).unwrap().part1(), 32000000.into());
+        assert_eq!(Solution::from_input(EXAMPLE_2).unwrap().part1(), 11687500.into());
+    }
+
+    // Counters of 1 and 2 flip-flops, whose conjunction sends a high pulse to hub every 2 and 4 presses
+    const COUNTERS: &str = "\
+broadcaster -> a, b
+%a -> na
+&na -> hub
+%b -> c
+%c -> nc
+&nc -> hub
+&hub -> rx";
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(COUNTERS).unwrap().part2(), 4.into());
+    }
+
+    #[test]
+    fn part2_irregular() {
+        // x only sends a low pulse when both b and c are on
+        let input = COUNTERS.replace("%b -> c\n%c -> nc\n&nc -> hub", "%b -> c, x\n%c -> x\n&x -> hub");
+        let Answer::Skipped(reason) = Solution::from_input(&input).unwrap().part2() else {
+            panic!("expected part 2 to be skipped");
+        };
+        assert!(reason.starts_with("the counter from b sends high pulses on presses [1, 2, 4]"), "{}", reason);
+
+        assert_eq!(Solution::from_input(EXAMPLE_1).unwrap().part2(), Answer::Skipped("no module sends to rx".to_string()));
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error(""), "");
+        assert_eq!(error("%a -> b"), "%a -> b");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,176 @@
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::render::{Canvas, Color};
+use crate::search::{bfs, Search};
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    map: Grid<char>,
+    starting_position: Point,
+}
+
+impl Solution {
+    fn get(&self, position: Point) -> char {
+        *self.map.get_wrapping(position)
+    }
+
+    /// Distances to the garden plots at most `max_steps` steps away, on the map repeated infinitely.
+    fn distances(&self, max_steps: usize) -> Search<Point> {
+        bfs([self.starting_position], |position| {
+            position.neighbors4().filter(|&neighbor| {
+                // Further than that, a plot can't be reached in time whatever the rocks
+                self.get(neighbor) != '#' && neighbor.manhattan(self.starting_position) <= max_steps
+            })
+        })
+    }
+
+    /// Number of garden plots reachable in exactly `max_steps` steps.
+    fn reachable(&self, max_steps: usize) -> usize {
+        count_reachable(&self.distances(max_steps), max_steps)
+    }
+
+    /// Same as [`Self::reachable`], extrapolated from the counts for three smaller numbers of steps.
+    fn extrapolate(&self, target_steps: usize) -> i64 {
+        // Because of the input shape, it follows a second degree polynomial
+        // Kinda hard to guess...
+
+        let height = self.map.height();
+        let offset = target_steps % height;
+        let magical_numbers = [offset, offset + height, offset + 2 * height];
+        let distances = self.distances(magical_numbers[2]);
+
+        // A plot can be reached in exactly the given number of steps when it is at most as far, and
+        // the same parity: if it's not the same parity, we will never be able to reach it, and if it has
+        // the same parity, but not the right distance, we can keep going back in forth between two tiles
+        let [s0, s1, s2] = magical_numbers.map(|steps| count_reachable(&distances, steps) as i64);
+
+        // Signed, as the counts need not be convex
+        let c = s0;
+        let a = (s2 - 2 * s1 + c) / 2;
+        let b = s1 - c - a;
+
+        let n = (target_steps / height) as i64;
+
+        a * n * n + b * n + c
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 21;
+    const TITLE: &'static str = "Step Counter";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(input, |c| matches!(c, '.' | '#' | 'S').then_some(c))?;
+        let start = map.find(|&c| c == 'S').ok_or_else(|| ParseError::new("no starting position", input))?;
+
+        Ok(Self {
+            map,
+            starting_position: start.into(),
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        self.reachable(64).into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        self.extrapolate(26501365).into()
+    }
+
+    fn render(&mut self) -> Option<Canvas> {
+        let steps = 64;
+        let distances = self.distances(steps);
+        let reachable = distances.distances().iter().filter(|&(_, &d)| d % 2 == steps % 2).map(|(&position, _)| position);
+
+        let mut canvas = Canvas::from_grid(&self.map, |&c| if c == '#' { Color::GRAY } else { Color::DARK });
+        canvas.overlay(reachable, Color::GREEN);
+        Some(canvas)
+    }
+}
+
+fn count_reachable(distances: &Search<Point>, steps: usize) -> usize {
+    distances.distances().values().filter(|&&d| d <= steps && d % 2 == steps % 2).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::differential::assert_agree;
+    use crate::generate;
+
+    const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+    #[test]
+    fn reachable() {
+        let solution = Solution::from_input(EXAMPLE).unwrap();
+        assert_eq!(solution.reachable(6), 16);
+        // The garden repeats infinitely
+        assert_eq!(solution.reachable(10), 50);
+        assert_eq!(solution.reachable(50), 1594);
+    }
+
+    /// Reference for the parity trick and the extrapolation: takes a step from every plot reached by
+    /// the previous one.
+    fn naive_reachable(solution: &Solution, steps: usize) -> usize {
+        let mut positions = HashSet::from([solution.starting_position]);
+        for _ in 0..steps {
+            positions = positions.iter().flat_map(|position| position.neighbors4()).filter(|&neighbor| solution.get(neighbor) != '#').collect();
+        }
+        positions.len()
+    }
+
+    #[test]
+    fn matches_naive() {
+        let solution = Solution::from_input(EXAMPLE).unwrap();
+        assert_agree(0..=30, |&steps| solution.reachable(steps), |&steps| naive_reachable(&solution, steps));
+
+        for seed in 0..10 {
+            let half = 3 + seed as usize % 4;
+            let solution = Solution::from_input(&generate::garden(&mut generate::rng(seed), half, 0.1)).unwrap();
+            let size = 2 * half + 1;
+            let targets = (2..=4).map(|n| half + n * size);
+            assert_agree(targets, |&steps| solution.extrapolate(steps), |&steps| naive_reachable(&solution, steps) as i64);
+        }
+    }
+
+    /// Rocks around the start of a generated garden, leaving it and its left and right neighbors.
+    fn walled_in(garden: &str, half: usize) -> String {
+        let mut rows = garden.lines().map(|line| line.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+        for y in [half - 1, half + 1] {
+            rows[y][half - 2..=half + 2].fill('#');
+        }
+        rows[half][half - 2] = '#';
+        rows[half][half + 2] = '#';
+        rows.iter().map(|row| row.iter().collect::<String>()).join("\n")
+    }
+
+    #[test]
+    fn not_convex() {
+        // The start alone at even steps, its two neighbors at odd ones: the counts go 1, 2, 1
+        let half = 3;
+        let solution = Solution::from_input(&walled_in(&generate::garden(&mut generate::rng(0), half, 0.1), half)).unwrap();
+        let size = 2 * half + 1;
+        let [s0, s1, s2] = [0, size, 2 * size].map(|steps| solution.reachable(steps) as i64);
+        assert!(s2 - 2 * s1 + s0 < 0);
+
+        // The fit still goes through the counts it is made of
+        let targets = (0..size).flat_map(|offset| (0..=2).map(move |n| offset + n * size));
+        assert_agree(targets, |&steps| solution.extrapolate(steps), |&steps| naive_reachable(&solution, steps) as i64);
+    }
+}
\ No newline at end of file
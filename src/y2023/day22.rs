@@ -1,34 +1,27 @@
 use std::collections::HashSet;
-use std::fs::read_to_string;
-use std::io::{self, Write};
 
 use itertools::Itertools;
 
+use crate::error::{parse_number, split_once};
+use crate::input::fields;
+use crate::trace::{Event, Tracer};
+use crate::{Answer, ParseError, Solver};
+
 pub struct Solution {
     lines: Vec<String>,
     bricks: Vec<Brick>,
     cannot_be_deintegrated: Vec<usize>,
     supports: Vec<HashSet<usize>>,
+    tracer: Tracer,
 }
 
 impl Solution {
-    pub fn init() -> Self {
-        let mut lines = Vec::new();
-        let mut bricks = Vec::new();
-        for (i, line) in read_to_string("inputs/day22.txt").unwrap().lines().enumerate() {
-            bricks.push(Brick::parse(line, i));
-            lines.push(line.to_string());
-        }
-
-        Self {
-            lines,
-            bricks,
-            cannot_be_deintegrated: vec![],
-            supports: vec![],
+    /// Makes the bricks fall, then finds which ones support which, once for both parts.
+    fn settle(&mut self) {
+        if !self.supports.is_empty() {
+            return;
         }
-    }
 
-    fn part1(&mut self) -> usize {
         // First step is to make them fall
         // 1) Let's compute, for each piece, the piece with the lowest z value, it will be the ordering to make the pieces fall
         let idx = self.bricks.iter().sorted_unstable_by_key(|brick| brick.lowest_z()).map(|brick| brick.id).collect::<Vec<_>>();
@@ -77,31 +70,66 @@ impl Solution {
                     // Now we can set on what we are resting
                     self.bricks[*id].resting_on = resting_on.iter().cloned().collect_vec();
 
+                    self.tracer.emit(|| {
+                        let brick = &self.bricks[*id];
+                        let ground = self.bricks.len();
+                        Event::new("land")
+                            .with("id", brick.id)
+                            .with("fall", brick.fall_distance)
+                            .with("z", brick.lowest_z())
+                            .with("resting_on", brick.resting_on.iter().copied().filter(|&id| id != ground).sorted().collect_vec())
+                    });
+
                     break;
                 }
             }
         }
 
-        let mut res = 0;
         for id in 0..self.bricks.len() {
             // We can remove a brick if, for all pieces standing on it there is at least one other piece carrying it
             // aka if for all brick in supports[id], len(resting_on) > 1
             // or if it doesn't support any brick
 
-            if supports[id].iter().all(|brick_id| self.bricks[*brick_id].resting_on.len() > 1) {
-                res += 1;
-            } else {
+            if !supports[id].iter().all(|brick_id| self.bricks[*brick_id].resting_on.len() > 1) {
                 // it's a part that "cannot" be deintegrated, let's store it for part 2 
                 self.cannot_be_deintegrated.push(id);
             }
         }
         
         self.supports = supports;
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 22;
+    const TITLE: &'static str = "Sand Slabs";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+        let mut bricks = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            bricks.push(Brick::parse(line, i)?);
+            lines.push(line.to_string());
+        }
+
+        Ok(Self {
+            lines,
+            bricks,
+            cannot_be_deintegrated: vec![],
+            supports: vec![],
+            tracer: Tracer::default(),
+        })
+    }
 
-        res
+    fn part1(&mut self) -> Answer {
+        self.settle();
+        (self.bricks.len() - self.cannot_be_deintegrated.len()).into()
     }
 
-    fn part2(&mut self) -> usize {
+    fn part2(&mut self) -> Answer {
+        self.settle();
+
         // cache[id] = # of bricks that can be deintegrated if we remove brick id
         let supported_by = self.bricks.iter().map(|brick| brick.resting_on.clone()).collect_vec();
 
@@ -124,27 +152,11 @@ impl Solution {
             res += tmp_res - 1;
         }
 
-        res
+        res.into()
     }
 
-    pub fn solve(&mut self) {
-        println!("========= DAY 22 ========");
-        
-        print!("Solving part 1: ");
-        io::stdout().flush().unwrap();
-
-        let start = std::time::Instant::now();
-        let part1 = self.part1();
-        let part1_time = start.elapsed();
-        println!("{:?} (took {:?})", part1, part1_time);
-
-        print!("Solving part 2: ");
-        io::stdout().flush().unwrap();
-        let start = std::time::Instant::now();
-        let part2 = self.part2();
-        let part2_time = start.elapsed();
-        println!("{:?} (took {:?})", part2, part2_time);
-        println!();
+    fn tracer(&mut self) -> Option<&mut Tracer> {
+        Some(&mut self.tracer)
     }
 }
 
@@ -173,10 +185,10 @@ impl Brick {
         self.blocks.iter().map(|(x, y, z)| (*x, *y, *z - self.fall_distance))
     }
 
-    fn parse(value: &str, id: usize) -> Self {
-        let (start, end) = value.split_once("~").unwrap();
-        let start = start.split(",").map(|x| x.parse::<usize>().unwrap()).collect::<Vec<_>>();
-        let end = end.split(",").map(|x| x.parse::<usize>().unwrap()).collect::<Vec<_>>();
+    fn parse(value: &str, id: usize) -> Result<Self, ParseError> {
+        let (start, end) = split_once(value, "~")?;
+        let start = Self::parse_position(start)?;
+        let end = Self::parse_position(end)?;
 
         let x1 = start[0].min(end[0]);
         let x2 = start[0].max(end[0]);
@@ -196,11 +208,54 @@ impl Brick {
             }
         }
 
-        Self {
+        Ok(Self {
             id,
             blocks,
             resting_on: vec![],
             fall_distance: 0,
+        })
+    }
+
+    /// `x,y,z` with `z` above the ground, which is at 0.
+    fn parse_position(text: &str) -> Result<[usize; 3], ParseError> {
+        let [x, y, z] = fields(text, ',').collect::<Vec<_>>()[..] else {
+            return Err(ParseError::new("expected `x,y,z`", text));
+        };
+        let position = [parse_number(x)?, parse_number(y)?, parse_number(z)?];
+        if position[2] == 0 {
+            return Err(ParseError::new("bricks must start above the ground", z));
         }
+        Ok(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 5.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 7.into());
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("1,0,1~1,2"), "1,2");
+        assert_eq!(error("1,0,0~1,2,0"), "0");
     }
 }
\ No newline at end of file
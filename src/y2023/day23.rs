@@ -0,0 +1,158 @@
+use std::collections::{HashSet, HashMap};
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::search::{bfs, longest_path};
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    map: Grid<char>,
+}
+
+impl Solution {
+    fn solve_part(&self, part: usize) -> usize {
+        let start = (1, 0);
+        let goal = (self.map.width() as isize - 2, self.map.height() as isize - 1);
+        
+        // List the intersections
+        let mut intersections = HashSet::new();
+        for ((x, y), c) in self.map.iter() {
+            if c != &'#' && self.neighbors(part, (x as isize, y as isize)).len() > 2 {
+                intersections.insert((x as isize, y as isize));
+            }
+        }
+
+        intersections.insert(start);
+        intersections.insert(goal);
+
+        // Create the graph (basically BFS from each intersection to the others)
+        let mut graph = HashMap::new();
+        for &intersection in &intersections {
+            let search = bfs([intersection], |&pos| {
+                // Stop the paths on the other intersections
+                if pos != intersection && intersections.contains(&pos) {
+                    vec![]
+                } else {
+                    self.neighbors(part, pos)
+                }
+            });
+
+            let edges = search
+                .distances()
+                .iter()
+                .filter(|(pos, _)| **pos != intersection && intersections.contains(pos))
+                .map(|(pos, dist)| (*pos, *dist))
+                .collect::<Vec<_>>();
+            graph.insert(intersection, edges);
+        }
+
+        // Run actual DFS 
+        longest_path(start, |pos| graph[pos].iter().copied(), |pos| *pos == goal).unwrap_or(0)
+    }
+
+    fn neighbors(&self, part: usize, (x, y): (isize, isize)) -> Vec<(isize, isize)> {
+        let directions = if part == 1 {
+            match self.map[(x as usize, y as usize)] {
+            '^' => vec![(0, -1)],
+            '>' => vec![(1, 0)],
+            'v' => vec![(0, 1)],
+            '<' => vec![(-1, 0)],
+            '.' | 'O' => vec![(0, 1), (0, -1), (1, 0), (-1, 0)],
+            _ => panic!("Invalid direction {} at ({}, {})", self.map[(x as usize, y as usize)], x, y),
+            }
+        } else {
+            vec![(0, 1), (0, -1), (1, 0), (-1, 0)]
+        };
+
+        let mut res = vec![];
+        for (dx, dy) in directions {
+            let nx = x + dx;
+            let ny = y + dy;
+            if self.map.get(Point::new(nx, ny)).is_some_and(|&c| c != '#') {
+                res.push((nx, ny));
+            }
+        }
+        res
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 23;
+    const TITLE: &'static str = "A Long Walk";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(input, |c| matches!(c, '#' | '.' | '^' | '>' | 'v' | '<').then_some(c))?;
+
+        // The walk goes from the second tile of the top row to the second to last of the bottom one
+        let (first, last) = (input.lines().next().unwrap(), input.lines().last().unwrap());
+        if map.width() < 3 {
+            return Err(ParseError::new("expected a map at least 3 tiles wide", first));
+        }
+        if map[(1, 0)] == '#' {
+            return Err(ParseError::new("expected the start to be open", &first[1..2]));
+        }
+        if map[(map.width() - 2, map.height() - 1)] == '#' {
+            return Err(ParseError::new("expected the goal to be open", &last[map.width() - 2..map.width() - 1]));
+        }
+
+        Ok(Self { map })
+    }
+
+    fn part1(&mut self) -> Answer {
+        self.solve_part(1).into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        self.solve_part(2).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 94.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 154.into());
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("#"), "#");
+        assert_eq!(error(".."), "..");
+        assert_eq!(error("###\n#.#"), "#");
+        assert_eq!(error("#.#\n#.#\n##."), "#");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,178 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use itertools::Itertools;
+
+use z3::ast::{Ast, Int, Real};
+
+use crate::error::{parse_number, split_once};
+use crate::input::fields;
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    lines: Vec<String>,
+    particles: Vec<Particle>,
+}
+
+impl Solution {
+    /// Number of pairs of hailstones whose paths cross inside the `[min, max]` test area.
+    fn count_intersections(&self, min: f64, max: f64) -> usize {
+        let mut res = 0;
+        for i in 0..self.particles.len() {
+            for j in i+1..self.particles.len() {
+                if let Some((x, y)) = self.particles[i].intersect_2d(&self.particles[j]) {
+                    if x >= min && y >= min && x <= max && y <= max {
+                        res += 1;
+                    }
+                }
+            }
+        }
+        res
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 24;
+    const TITLE: &'static str = "Never Tell Me The Odds";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+        for line in input.lines() {
+            lines.push(line.to_string());
+        }
+
+        let particles = input.lines().map(|l| l.parse()).collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            lines,
+            particles,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        self.count_intersections(200000000000000., 400000000000000.).into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        let ctx = z3::Context::new(&z3::Config::new());
+        let s = z3::Solver::new(&ctx);
+        let [fx,fy,fz,fdx,fdy,fdz] = ["fx","fy","fz","fdx","fdy","fdz"].map(|v| Real::new_const(&ctx, v));
+      
+        let zero = Int::from_i64(&ctx, 0).to_real();
+        // Need only the first 3 particles
+        for (i, part) in self.particles.iter().take(3).enumerate() {
+          let [x,y,z,dx,dy,dz] = [part.x,part.y,part.z,part.vx,part.vy,part.vz].map(|v| Int::from_i64(&ctx, v as _).to_real());
+          let t = Real::new_const(&ctx, format!("t{i}"));
+          s.assert(&t.ge(&zero));
+          s.assert(&((&x + &dx * &t)._eq(&(&fx + &fdx * &t))));
+          s.assert(&((&y + &dy * &t)._eq(&(&fy + &fdy * &t))));
+          s.assert(&((&z + &dz * &t)._eq(&(&fz + &fdz * &t))));
+        }
+        assert_eq!(s.check(), z3::SatResult::Sat);
+        let res = s.get_model().unwrap().eval(&(&fx + &fy + &fz), true).unwrap();
+
+        res.as_real().unwrap().0.into()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Particle {
+    x: f64,
+    y: f64,
+    z: f64,
+    vx: f64,
+    vy: f64,
+    vz: f64,
+}
+
+impl FromStr for Particle {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (position, speed) = split_once(value, " @ ")?;
+        let (x, y, z) = parse_vector(position)?;
+        let (vx, vy, vz) = parse_vector(speed)?;
+        Ok(Self { x, y, z, vx, vy, vz })
+    }
+}
+
+fn parse_vector(text: &str) -> Result<(f64, f64, f64), ParseError> {
+    fields(text, ',')
+        .map(parse_number::<f64>)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .collect_tuple()
+        .ok_or_else(|| ParseError::new("expected `<x>, <y>, <z>`", text))
+}
+
+impl Display for Particle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}, {} ({}, {}, {})", self.x, self.y, self.z, self.vx, self.vy, self.vz)
+    }
+}
+
+impl Particle {
+    fn intersect_2d(&self, other: &Self) -> Option<(f64, f64)>{
+        let den = self.vx * other.vy - self.vy * other.vx;
+        if den == 0.0 {
+            // println!("Parallel");
+            return None;
+        }
+
+        let t = ((other.x - self.x) * other.vy + (self.y - other.y) * other.vx) / den;
+        let u = ((other.x - self.x) * self.vy + (self.y - other.y) * self.vx) / den;
+
+        if t < 0. || u < 0. {
+            // println!("Intersect in the past");
+            return None;
+        }
+
+        let x = self.x + t * self.vx;
+        let y = self.y + t * self.vy;
+
+        Some((x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().count_intersections(7., 27.), 2);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 47.into());
+    }
+
+    #[test]
+    fn intersect_2d() {
+        let particles = EXAMPLE.lines().map(|line| line.parse::<Particle>().unwrap()).collect::<Vec<_>>();
+        let close = |(x, y): (f64, f64), (ex, ey): (f64, f64)| (x - ex).abs() < 1e-3 && (y - ey).abs() < 1e-3;
+
+        assert!(close(particles[0].intersect_2d(&particles[1]).unwrap(), (14.333, 15.333)));
+        assert!(close(particles[0].intersect_2d(&particles[3]).unwrap(), (6.2, 19.4)));
+        // Parallel
+        assert_eq!(particles[1].intersect_2d(&particles[2]), None);
+        // Crossed in the past
+        assert_eq!(particles[0].intersect_2d(&particles[4]), None);
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("19, 13, 30 -2, 1, -2"), "19, 13, 30 -2, 1, -2");
+        assert_eq!(error("19, 13 @ -2, 1, -2"), "19, 13");
+        assert_eq!(error("19, x, 30 @ -2, 1, -2"), "x");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,159 @@
+use itertools::Itertools;
+
+use std::collections::{HashMap, HashSet};
+use rand::prelude::*;
+
+use crate::error::split_once;
+use crate::search::{bfs, bfs_until};
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    graph: HashMap<String, Vec<String>>,
+}
+
+impl Solution {
+    /// Product of the sizes of the two groups left once `edges` are cut, 0 if they are still connected.
+    fn split(&self, edges: &[(String, String)]) -> usize {
+        let Some((start, _)) = edges.first() else {
+            return 0;
+        };
+
+        // Remove the edges from a copy of the graph, so that a wrong guess doesn't spoil the next tries
+        let mut cut = self.graph.clone();
+        for (a, b) in edges {
+            cut.get_mut(a).unwrap().retain(|x| x != b);
+            cut.get_mut(b).unwrap().retain(|x| x != a);
+        }
+
+        // Run BFS on one of the remaining nodes
+        let visited = bfs([start.clone()], |element| cut[element].iter().cloned()).states().count();
+        visited * (self.graph.len() - visited)
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 25;
+    const TITLE: &'static str = "Snowverload";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut graph = HashMap::new();
+        // let mut edges = HashSet::new();
+
+        for line in input.lines() {
+            let (a, end) = split_once(line, ": ")?;
+            for b in end.split_whitespace() {
+                let a = a.to_string();
+                let b = b.to_string();
+
+                graph.entry(a.clone()).or_insert(Vec::new()).push(b.clone());
+                graph.entry(b.clone()).or_insert(Vec::new()).push(a.clone());
+
+                // edges.insert((a.to_string(), b.to_string()));
+                // edges.insert((b.to_string(), a.to_string()));
+            }
+        }
+
+        if graph.is_empty() {
+            return Err(ParseError::new("expected at least one connection", input));
+        }
+
+        Ok(Self {
+            graph,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        let graph = &self.graph;
+        let nodes = graph.keys().map(|x| x.clone()).collect_vec();
+
+        loop {
+            // Take x random pairs of nodes and find the shortest path between them
+            // Count the number of times each edge is used
+            let mut edges_occurences = HashMap::new();
+            for _ in 0..10 {
+                // Choose two random nodes
+                let a = nodes.choose(&mut rand::thread_rng()).unwrap().clone();
+                let b = nodes.choose(&mut rand::thread_rng()).unwrap().clone();
+                if a != b {
+                    let search = bfs_until([a], |element| graph[element].iter().cloned(), |element| *element == b);
+                    let path = search.path(&b).unwrap();
+                    for edge in path.windows(2) {
+                        let mut edge = (edge[0].clone(), edge[1].clone());
+                        if !edges_occurences.contains_key(&edge) {
+                            edge = (edge.1, edge.0);
+                        }
+
+                        *edges_occurences.entry(edge).or_insert(0) += 1;
+                    }
+                
+                }
+            }
+
+            // Select the 3 most used edges between 6 different nodes
+            let mut used_nodes = HashSet::new();
+            let mut edges = vec![];
+            for ((a, b), _occurences) in edges_occurences.iter().sorted_by_key(|(_, occurences)| -**occurences) {
+                if used_nodes.contains(&a) || used_nodes.contains(&b) {
+                    continue;
+                }
+
+                edges.push((a.clone(), b.clone()));
+                used_nodes.insert(a);
+                used_nodes.insert(b);
+                if edges.len() >= 3 {
+                    break;
+                }
+            }
+
+            let n = self.split(&edges);
+            if n != 0 {
+                break n.into();
+            }
+        }
+    }
+
+    fn part2(&mut self) -> Answer {
+        "Merry Christmas!".to_string().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 54.into());
+    }
+
+    #[test]
+    fn retries() {
+        let solution = Solution::from_input(EXAMPLE).unwrap();
+        let edges = |pairs: [(&str, &str); 3]| pairs.map(|(a, b)| (a.to_string(), b.to_string()));
+        assert_eq!(solution.split(&edges([("jqt", "rhn"), ("rsh", "frs"), ("xhk", "hfx")])), 0);
+        assert_eq!(solution.split(&edges([("hfx", "pzl"), ("bvb", "cmg"), ("nvd", "jqt")])), 54);
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error(""), "");
+        assert_eq!(error("jqt rhn"), "jqt rhn");
+    }
+}
\ No newline at end of file
@@ -1,45 +1,36 @@
-use std::fs::read_to_string;
-use std::io::{self, Write};
-
 use std::collections::{HashSet, HashMap};
 
+use crate::grid::Grid;
+use crate::{Answer, ParseError, Solver};
+
 pub struct Solution {
-    lines: Vec<String>,
+    grid: Grid<char>,
 }
 
-impl Solution {
-    pub fn init() -> Self {
-        let mut lines = Vec::new();
-        for line in read_to_string("inputs/day3.txt").unwrap().lines() {
-            lines.push(line.to_string());
-        }
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 3;
+    const TITLE: &'static str = "Gear Ratios";
 
-        Self {
-            lines,
-        }
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            grid: Grid::parse(input, Some)?,
+        })
     }
 
-    fn part1(&mut self) -> u32{
+    fn part1(&mut self) -> Answer {
         let mut sum = 0;
 
         // Check positions valid given symbols
         let mut valid_positions = HashSet::new();
-        for (y, line) in self.lines.iter().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                if c != '.' && c.is_ascii_punctuation() {
-                    for dx in 0..3 {
-                        for dy in 0..3 {
-                            valid_positions.insert(((x+dx).saturating_sub(1), (y+dy).saturating_sub(1)));
-                        }
-                    }
-                }
-            }
+        for position in self.grid.positions(|&c| c != '.' && c.is_ascii_punctuation()) {
+            valid_positions.extend(self.grid.neighbors8(position));
         }
 
         // Read numbers
-        for (y, line) in self.lines.iter().enumerate() {
+        for (y, row) in self.grid.rows().enumerate() {
             let mut x = 0;
-            let mut chars = line.chars();
+            let mut chars = row.iter().copied();
             while let Some(c) = chars.next() {
                 if c.is_digit(10) {
                     let mut valid_position = valid_positions.contains(&(x, y));
@@ -67,30 +58,24 @@ impl Solution {
             }
         }
 
-        sum
+        sum.into()
     }
 
-    fn part2(&mut self) -> u32{
+    fn part2(&mut self) -> Answer {
         // Check positions valid given symbols
         let mut gear_positions = HashMap::new();
         let mut gear_ratios: HashMap<(usize, usize), (u32, u32)> = HashMap::new();
-        for (y, line) in self.lines.iter().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                if c == '*' {
-                    for dx in 0..3 {
-                        for dy in 0..3 {
-                            gear_positions.insert(((x+dx).saturating_sub(1), (y+dy).saturating_sub(1)), (x, y));
-                        }
-                    }
-                    gear_ratios.insert((x, y), (0, 1));
-                }
+        for gear in self.grid.positions(|&c| c == '*') {
+            for position in self.grid.neighbors8(gear) {
+                gear_positions.insert(position, gear);
             }
+            gear_ratios.insert(gear, (0, 1));
         }
 
         // Read numbers
-        for (y, line) in self.lines.iter().enumerate() {
+        for (y, row) in self.grid.rows().enumerate() {
             let mut x = 0;
-            let mut chars = line.chars();
+            let mut chars = row.iter().copied();
             while let Some(c) = chars.next() {
                 if c.is_digit(10) {
                     let mut gear_position = gear_positions.get(&(x, y));
@@ -121,25 +106,33 @@ impl Solution {
             }
         }
 
-        gear_ratios.values().into_iter().filter(|(num, _ratio)| *num == 2).map(|(_num, ratio)| *ratio).sum()
+        gear_ratios.values().into_iter().filter(|(num, _ratio)| *num == 2).map(|(_num, ratio)| *ratio).sum::<u32>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 4361.into());
     }
 
-    pub fn solve(&mut self) {
-        println!("========= DAY 3 ========");
-        print!("Solving part 1: ");
-        io::stdout().flush().unwrap();
-
-        let start = std::time::Instant::now();
-        let part1 = self.part1();
-        let part1_time = start.elapsed();
-        println!("{:?} (took {:?})", part1, part1_time);
-
-        print!("Solving part 2: ");
-        io::stdout().flush().unwrap();
-        let start = std::time::Instant::now();
-        let part2 = self.part2();
-        let part2_time = start.elapsed();
-        println!("{:?} (took {:?})", part2, part2_time);
-        println!();
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 467835.into());
     }
 }
\ No newline at end of file
@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::error::split_once;
+use crate::input;
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    lines: Vec<String>,
+    cards: Vec<Card>,
+}
+
+struct Card {
+    winning_numbers: HashSet<u32>,
+    numbers: HashSet<u32>,
+    in_common: usize,
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (winning_numbers, numbers) = split_once(split_once(line, ": ")?.1, " | ")?;
+        let winning_numbers: HashSet<_> = input::numbers::<u32>(winning_numbers)?.into_iter().collect();
+        let numbers: HashSet<_> = input::numbers::<u32>(numbers)?.into_iter().collect();
+
+        Ok(Self {
+            in_common: winning_numbers.intersection(&numbers).count(),
+            winning_numbers, 
+            numbers,
+        })
+    }
+}
+
+impl Card {
+    fn score(&self) -> u32 {
+        if self.in_common > 0 {
+            2u32.pow(self.in_common as u32 - 1)
+        } else {
+            0
+        }
+    }
+
+    fn num_matching(&self) -> usize {
+        self.in_common
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+        let mut cards = Vec::new();
+        for line in input.lines() {
+            cards.push(line.parse()?);
+            lines.push(line.to_string());
+        }
+
+        Ok(Self {
+            lines,
+            cards,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        self.cards.iter().map(|card| card.score()).sum::<u32>().into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        let mut num_cards = vec![1u64; self.cards.len()];
+
+        for card_number in 0..self.cards.len() {
+            let num_matching = &self.cards[card_number].num_matching();
+            let amount = num_cards[card_number];
+
+            for next_card in card_number+1..card_number+1+num_matching {
+                num_cards[next_card] += amount;
+            }
+        }
+
+        num_cards.iter().sum::<u64>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 13.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 30.into());
+    }
+
+    #[test]
+    fn card_score() {
+        let scores = EXAMPLE.lines().map(|line| line.parse::<Card>().unwrap().score()).collect::<Vec<_>>();
+        assert_eq!(scores, vec![8, 2, 2, 1, 0, 0]);
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("Card 1: 41 x | 83 86"), "x");
+        assert_eq!(error("Card 1: 41 48 83 86"), "41 48 83 86");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,236 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+
+use std::str::FromStr;
+
+use crate::error::{expect_prefix, split_once};
+use crate::input::{blocks, numbers};
+use crate::interval::{Interval, IntervalSet};
+use crate::{Answer, ParseError, Solver};
+
+#[derive(Debug, Clone)]
+pub struct Mapping {
+    pub source: String,
+    pub destination: String,
+
+    pub ranges: Vec<Range>,
+}
+
+/// Values of `source` move by `offset`.
+#[derive(Debug, Clone)]
+pub struct Range {
+    source: Interval,
+    offset: i64,
+}
+
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let values = numbers::<i64>(line)?;
+        let [destination_start, source_start, length] = values[..] else {
+            return Err(ParseError::new("expected `<destination> <source> <length>`", line));
+        };
+
+        Ok(Self {
+            source: Interval::new(source_start, source_start + length),
+            offset: destination_start - source_start,
+        })
+    }
+}
+
+impl Range {
+    fn forward_convert(&self, value: i64) -> Option<i64> {
+        self.source.contains(value).then_some(value + self.offset)
+    }
+}
+
+impl FromStr for Mapping {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut lines = value.lines();
+
+        // Get the destination names
+        let header = lines.next().unwrap_or(value);
+        let (source, destination) = split_once(
+            header.strip_suffix(" map:").ok_or_else(|| ParseError::new("expected `<source>-to-<destination> map:`", header))?,
+            "-to-",
+        )?;
+
+        // Get the ranges
+        let ranges = lines.map(|line| line.parse()).collect::<Result<_, _>>()?;
+
+        Ok(Mapping { source: source.to_string(), destination: destination.to_string(), ranges })
+    }
+}
+
+impl Mapping {
+    fn forward_convert(&self, value: i64) -> i64 {
+        match self.ranges.iter().filter_map(|x| x.forward_convert(value)).exactly_one() {
+            Ok(converted_val) => converted_val,
+            Err(..) => value
+        }
+    }
+
+    fn forward_convert_set(&self, values: &IntervalSet) -> IntervalSet {
+        values.map(self.ranges.iter().map(|range| (range.source, range.offset)))
+    }
+}
+
+pub struct Solution {
+    lines: Vec<String>,
+    content: String,
+    mappings: HashMap<String, Mapping>,
+    seeds: Vec<i64>,
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+
+        let content = input.to_string();
+        for line in content.lines() {
+            lines.push(line.to_string());
+        }
+
+        // Data parsing
+        let [seeds_str, mapping_blocks @ ..] = &blocks(input).collect_vec()[..] else {
+            return Err(ParseError::new("expected the seeds", input));
+        };
+
+        let seeds: Vec<i64> = numbers(expect_prefix(seeds_str, "seeds:")?)?;
+        if seeds.is_empty() {
+            return Err(ParseError::new("expected at least one seed", seeds_str));
+        }
+        // Part 2 reads them as ranges
+        if !seeds.len().is_multiple_of(2) {
+            return Err(ParseError::new("expected seeds in pairs of a start and a length", seeds_str));
+        }
+        let mut mappings = HashMap::new();
+        
+        for mapping_block in mapping_blocks {
+            let mapping: Mapping = mapping_block.parse()?;
+            mappings.insert(mapping.source.clone(), mapping);
+        }
+
+        Ok(Self {
+            lines,
+            content,
+            mappings,
+            seeds,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        let mut res = i64::MAX;
+        for seed in &self.seeds {
+            let mut value = *seed;
+            let mut source = "seed";
+            
+            while let Some(mapping) = self.mappings.get(source) {
+                value = mapping.forward_convert(value);
+                source = mapping.destination.as_str();
+            }
+
+            res = res.min(value);
+        }
+        res.into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        // Convert whole ranges of seeds at once
+        let mut values: IntervalSet = self.seeds.iter().tuples().map(|(&start, &length)| Interval::new(start, start + length)).collect();
+        let mut source = "seed";
+
+        while let Some(mapping) = self.mappings.get(source) {
+            values = mapping.forward_convert_set(&values);
+            source = mapping.destination.as_str();
+        }
+
+        values.min().unwrap().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 35.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 46.into());
+    }
+
+    #[test]
+    fn every_seed() {
+        // Splitting a range of seeds on the bounds of a map must keep every seed, down to ranges of one
+        for seeds in ["seeds: 79 14 55 13", "seeds: 79 1", "seeds: 0 100", "seeds: 97 3 49 2"] {
+            let input = EXAMPLE.replacen("seeds: 79 14 55 13", seeds, 1);
+            let mut solution = Solution::from_input(&input).unwrap();
+            let expected = solution.part2();
+            solution.seeds = solution.seeds.iter().tuples().flat_map(|(&start, &length)| start..start + length).collect();
+            assert_eq!(solution.part1(), expected, "{}", seeds);
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error(""), "");
+        assert_eq!(error(" \n\n"), "");
+        assert_eq!(error("seed: 79 14"), "seed: ");
+        assert_eq!(error("seeds:\n\nseed-to-soil map:\n50 98 2"), "seeds:");
+        assert_eq!(error("seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2"), "seeds: 79 14 55");
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = format!("\u{feff}{}\r\n\r\n", EXAMPLE.replace('\n', "\r\n"));
+        assert_eq!(Solution::from_input(&input).unwrap().part1(), 35.into());
+    }
+}
\ No newline at end of file
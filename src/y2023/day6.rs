@@ -0,0 +1,146 @@
+use itertools::Itertools;
+
+use crate::error::{expect_prefix, split_once};
+use crate::input::numbers;
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    times_distances: Vec<(i64, i64)>,
+    /// The races read as one for part 2, with the spaces between their digits removed.
+    race: (i64, i64),
+}
+
+impl Solution {
+    fn find_beaten_records(&self, total_time: i64, record_distance: i64) -> i64 {
+        let delta_sqr = ((total_time.pow(2) - 4 * record_distance) as f64).sqrt();
+        // Strictly beat the record: exact roots only tie it
+        let start = ((total_time as f64 - delta_sqr) / 2.).floor() as i64 + 1;
+        let end = ((total_time as f64 + delta_sqr) / 2.).ceil() as i64 - 1;
+
+        // No way to win when the record can only be tied, or not even reached
+        (end - start + 1).max(0)
+    }
+}
+
+/// Numbers of `text` read as one.
+fn joined(text: &str) -> Option<i64> {
+    text.split_whitespace().collect::<String>().parse().ok()
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (a, b) = split_once(input, "\n")?;
+        let (times_str, distances_str) = (expect_prefix(a, "Time:")?, expect_prefix(b, "Distance:")?);
+        let times = numbers::<i64>(times_str)?;
+        let distances = numbers::<i64>(distances_str)?;
+        if times.len() != distances.len() {
+            return Err(ParseError::new("expected as many distances as times", b));
+        }
+
+        // Squaring the time and quadrupling the distance must not overflow, which then holds for
+        // each race alone too
+        let time = joined(times_str).filter(|time| time.checked_mul(*time).is_some());
+        let time = time.ok_or_else(|| ParseError::new("races too long put together", times_str.trim()))?;
+        let distance = joined(distances_str).filter(|distance| distance.checked_mul(4).is_some());
+        let distance = distance.ok_or_else(|| ParseError::new("distances too long put together", distances_str.trim()))?;
+
+        Ok(Self {
+            times_distances: times.into_iter().zip(distances).collect_vec(),
+            race: (time, distance),
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        let mut res: i64 = 1;
+        for (total_time, record_distance) in &self.times_distances {
+            res *= self.find_beaten_records(*total_time, *record_distance);
+        }
+        res.into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        let (time, record_distance) = self.race;
+        self.find_beaten_records(time, record_distance).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::assert_agree;
+    use crate::generate;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 288.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 71503.into());
+    }
+
+    #[test]
+    fn ties() {
+        // Holding 10 or 20ms of a 30ms race only ties a 200mm record
+        let solution = Solution::from_input(EXAMPLE).unwrap();
+        assert_eq!(solution.find_beaten_records(30, 200), 9);
+        assert_eq!(solution.find_beaten_records(8, 16), 0);
+        assert_eq!(solution.find_beaten_records(8, 17), 0);
+        assert_eq!(solution.find_beaten_records(8, 15), 1);
+        assert_eq!(solution.find_beaten_records(8, 14), 3);
+    }
+
+    /// Reference for the quadratic formula: tries every time to hold the button for.
+    fn naive_beaten_records(total_time: i64, record_distance: i64) -> i64 {
+        (0..=total_time).filter(|hold| hold * (total_time - hold) > record_distance).count() as i64
+    }
+
+    fn naive(input: &str) -> (Answer, Answer) {
+        let [times, distances] = [0, 1].map(|index| input.lines().nth(index).unwrap().split_whitespace().skip(1).collect::<Vec<_>>());
+        let part1 = times
+            .iter()
+            .zip(&distances)
+            .map(|(time, distance)| naive_beaten_records(time.parse().unwrap(), distance.parse().unwrap()))
+            .product::<i64>();
+        let part2 = naive_beaten_records(times.concat().parse().unwrap(), distances.concat().parse().unwrap());
+        (part1.into(), part2.into())
+    }
+
+    #[test]
+    fn matches_naive() {
+        // Every race short enough, including the records only tied by exact roots
+        let solution = Solution::from_input(EXAMPLE).unwrap();
+        let races = (1..=60).flat_map(|time: i64| (0..=time * time / 4 + 1).map(move |distance| (time, distance)));
+        assert_agree(races, |&(time, distance)| solution.find_beaten_records(time, distance), |&(time, distance)| naive_beaten_records(time, distance));
+
+        let inputs = (0..50).map(|seed| generate::races(&mut generate::rng(seed), 1 + seed as usize % 3, 60));
+        assert_agree(
+            inputs,
+            |input| {
+                let mut solution = Solution::from_input(input).unwrap();
+                (solution.part1(), solution.part2())
+            },
+            |input| naive(input),
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("Time: 7 15"), "Time: 7 15");
+        assert_eq!(error("Time: 7 15\nDistance: 9"), "Distance: 9");
+        // 3037000500 squared is just past an i64
+        assert_eq!(error("Time: 30370 00500\nDistance: 9 40"), "30370 00500");
+        assert_eq!(error("Time: 7 15\nDistance: 2305843009 213693952"), "2305843009 213693952");
+        assert_eq!(error("Time: 7 15\nDistance: 99999999999 99999999999"), "99999999999 99999999999");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use itertools::Itertools;
+
+use crate::error::{parse_number, split_once};
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    lines: Vec<String>,
+    hands: Vec<Hand>,
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 7;
+    const TITLE: &'static str = "Camel Cards";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+        for line in input.lines() {
+            lines.push(line.to_string());
+        }
+
+        Ok(Self {
+            hands: input.lines().map(|line| line.parse()).collect::<Result<_, _>>()?,
+            lines,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        self.hands
+            .iter()
+            .cloned()
+            .sorted()
+            .enumerate()
+            .map(|(rank, hand)| (rank+1) * hand.bid)
+            .sum::<usize>().into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        self.hands
+            .iter()
+            .map(|hand| hand.with_jokers())
+            .sorted()
+            .enumerate()
+            .map(|(rank, hand)| (rank+1) * hand.bid)
+            .sum::<usize>().into()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Hand {
+    cards: [Card; 5],
+    bid: usize,
+    hand_type: HandType,
+}
+
+impl Hand {
+    fn new(cards: [Card; 5], bid: usize) -> Self {
+        // Determine card type
+        let joker_count = cards.iter().filter(|card| **card == Card::Joker).count(); // PART 2
+        let frequencies = if joker_count != 5 {
+            let frequencies_map = cards.iter().filter(|card| **card != Card::Joker).fold(HashMap::new(), |mut map, val|{
+                map.entry(val)
+                .and_modify(|frq|*frq+=1)
+                .or_insert(1);
+                map
+            });
+            let mut frequencies = frequencies_map.values().sorted().rev().map(|x| *x).collect_vec();
+            frequencies[0] += joker_count;
+            frequencies
+        } else {
+            vec![5]
+        };
+
+        let hand_type = match &frequencies[..] {
+            [5] => HandType::FiveOfAKind,
+            [4, 1] => HandType::FourOfAKind,
+            [3, 2] => HandType::FullHouse,
+            [3, 1, 1] => HandType::ThreeOfAKind,
+            [2, 2, 1] => HandType::TwoPair,
+            [2, 1, 1, 1] => HandType::OnePair,
+            [1, 1, 1, 1, 1] => HandType::HighCard,
+            _ => unreachable!("Impossible frequencies: {:?} for hand {:?}", frequencies, cards),
+        };
+
+        Self {
+            cards,
+            bid,
+            hand_type,
+        }
+    }
+
+    // PART 2
+    fn with_jokers(&self) -> Self {
+        Self::new(self.cards.map(|card| if card == Card::J { Card::Joker } else { card }), self.bid)
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (cards_str, bid) = split_once(line, " ")?;
+        let cards = cards_str
+            .char_indices()
+            .map(|(i, card)| Card::try_from(card).map_err(|_| ParseError::new("unknown card", &cards_str[i..i + card.len_utf8()])))
+            .collect::<Result<Vec<_>, _>>()?;
+        let cards = cards.try_into().map_err(|_| ParseError::new("expected 5 cards", cards_str))?;
+
+        Ok(Self::new(cards, parse_number(bid)?))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self.hand_type.cmp(&other.hand_type) {
+            std::cmp::Ordering::Equal => {
+                // If equal, compare the cards themselves
+                for i in 0..5 {
+                    let a = &self.cards[i];
+                    let b = &other.cards[i];
+
+                    match a.cmp(b) {
+                        std::cmp::Ordering::Equal => (),
+                        ordering => return ordering,
+                    };
+                }
+                std::cmp::Ordering::Equal
+            }
+            ordering => ordering,
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+enum Card {
+    Joker, // PART 2
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Height,
+    Nine,
+    T,
+    J, // PART 1
+    Q,
+    K,
+    A,
+}
+
+impl TryFrom<char> for Card {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Self::Two),
+            '3' => Ok(Self::Three),
+            '4' => Ok(Self::Four),
+            '5' => Ok(Self::Five),
+            '6' => Ok(Self::Six),
+            '7' => Ok(Self::Seven),
+            '8' => Ok(Self::Height),
+            '9' => Ok(Self::Nine),
+            'T' => Ok(Self::T),
+            'J' => Ok(Self::J),
+            'Q' => Ok(Self::Q),
+            'A' => Ok(Self::A),
+            'K' => Ok(Self::K),
+            _ => Err(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 6440.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 5905.into());
+    }
+
+    #[test]
+    fn hand_ordering() {
+        let hand = |cards: &str| cards.parse::<Hand>().unwrap();
+
+        // Different types
+        assert!(hand("32T3K 1") < hand("KK677 1"));
+        assert!(hand("KK677 1") < hand("T55J5 1"));
+        // Same type, first differing card decides
+        assert!(hand("KTJJT 1") < hand("KK677 1"));
+        assert!(hand("T55J5 1") < hand("QQQJA 1"));
+        assert!(hand("33332 1") > hand("2AAAA 1"));
+
+        // Jokers upgrade the type but are the weakest card
+        assert!(hand("QQQJA 1").with_jokers() < hand("KTJJT 1").with_jokers());
+        assert!(hand("JKKK2 1").with_jokers() < hand("QQQQ2 1").with_jokers());
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("32T3K"), "32T3K");
+        assert_eq!(error("32T3X 765"), "X");
+        assert_eq!(error("32T3 765"), "32T3");
+        assert_eq!(error("32T3K x"), "x");
+    }
+}
\ No newline at end of file
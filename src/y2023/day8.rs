@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::input::blocks;
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    instructions: Vec<Instruction>,
+    tree: Tree,
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let [instructions_str, nodes_str] = blocks(input).collect::<Vec<_>>()[..] else {
+            return Err(ParseError::new("expected instructions and nodes separated by a blank line", input));
+        };
+
+        let instructions = instructions_str
+            .char_indices()
+            .map(|(i, c)| Instruction::parse(&instructions_str[i..i + c.len_utf8()]))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            instructions,
+            tree: nodes_str.parse()?,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        let (instructions, tree) = (&self.instructions, &self.tree);
+        // Networks for ghosts only, like the example of part 2, have no way for a human
+        if !tree.nodes.contains_key("AAA") || !tree.nodes.contains_key("ZZZ") {
+            return Answer::Skipped("no `AAA` or `ZZZ` node".to_string());
+        }
+
+        let num_instructions = instructions.len();
+        let mut instructions_counter = 0;
+        let mut current_node = "AAA";
+        let goal = "ZZZ";
+
+        while current_node != goal {
+            let node = &tree.nodes[current_node];
+            current_node = match instructions[instructions_counter % num_instructions] {
+                Instruction::Left => &node.left,
+                Instruction::Right => &node.right,
+            };
+            instructions_counter += 1;
+        }
+
+        instructions_counter.into()
+    }
+
+    fn part2(&mut self) -> Answer {
+        let (instructions, tree) = (&self.instructions, &self.tree);
+
+        tree
+            .nodes
+            .keys()
+            .filter(|name| name.ends_with('A'))
+            .map(|start_node| {
+                let mut current_node = start_node;
+                let mut instructions_counter = 0;
+                while !current_node.ends_with('Z') {
+                    let node = &tree.nodes[current_node];
+                    current_node = match instructions[instructions_counter % instructions.len()] {
+                        Instruction::Left => &node.left,
+                        Instruction::Right => &node.right,
+                    };
+                    instructions_counter += 1;
+                }
+                instructions_counter
+            })
+            .fold(1, |acc, n| num::integer::lcm(acc, n)).into()
+    }
+}
+
+
+#[derive(Debug)]
+pub struct Node {
+    value: String,
+    left: String,
+    right: String,
+}
+
+impl Node {
+    fn new(value: String, left: String, right: String) -> Self {
+        Self { value, left, right }
+    }
+}
+
+#[derive(Debug)]
+pub struct Tree {
+    nodes: HashMap<String, Node>,
+}
+
+impl FromStr for Tree {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let regex = Regex::new(r"^(\w+) = \((\w+), (\w+)\)$").unwrap();
+        let mut nodes = HashMap::new();
+        let mut ways = vec![];
+        for line in text.lines() {
+            let captures = regex
+                .captures(line)
+                .ok_or_else(|| ParseError::new("expected a node like `AAA = (BBB, CCC)`", line))?;
+            let node = Node::new(captures[1].to_string(), captures[2].to_string(), captures[3].to_string());
+            nodes.insert(captures[1].to_string(), node);
+            ways.extend([2, 3].map(|i| &line[captures.get(i).unwrap().range()]));
+        }
+
+        if let Some(way) = ways.into_iter().find(|way| !nodes.contains_key(*way)) {
+            return Err(ParseError::new("unknown node", way));
+        }
+        Ok(Self { nodes })
+    }
+}
+
+enum Instruction {
+    Left,
+    Right,
+}
+
+impl Instruction {
+    fn parse(text: &str) -> Result<Self, ParseError> {
+        match text {
+            "L" => Ok(Instruction::Left),
+            "R" => Ok(Instruction::Right),
+            _ => Err(ParseError::new("expected `L` or `R`", text)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+    use crate::differential::{assert_agree, compare};
+    use crate::generate;
+
+    const EXAMPLE_1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+    const EXAMPLE_2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+    const EXAMPLE_3: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE_1).unwrap().part1(), 2.into());
+        assert_eq!(Solution::from_input(EXAMPLE_2).unwrap().part1(), 6.into());
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE_3).unwrap().part2(), 6.into());
+    }
+
+    #[test]
+    fn ghosts_only() {
+        assert_eq!(Solution::from_input(EXAMPLE_3).unwrap().part1(), Answer::Skipped("no `AAA` or `ZZZ` node".to_string()));
+    }
+
+    /// Longer than any generated ghost takes to enter its loop and for all the loops to line up.
+    const LIMIT: usize = 1_000;
+
+    /// Steps up to [`LIMIT`] after which the ghost leaving `start` stands on an end.
+    fn ends(Solution { instructions, tree }: &Solution, start: &str) -> Vec<usize> {
+        let mut ghost = start;
+        let mut ends = vec![];
+        for steps in 1..=LIMIT {
+            let node = &tree.nodes[ghost];
+            ghost = match instructions[(steps - 1) % instructions.len()] {
+                Instruction::Left => &node.left,
+                Instruction::Right => &node.right,
+            };
+            if ghost.ends_with('Z') {
+                ends.push(steps);
+            }
+        }
+        ends
+    }
+
+    fn starts(solution: &Solution) -> Vec<&String> {
+        solution.tree.nodes.keys().filter(|name| name.ends_with('A')).collect_vec()
+    }
+
+    /// Reference for the LCM: the first step on which every ghost stands on an end, if any.
+    fn naive_part2(input: &str) -> Option<Answer> {
+        let solution = Solution::from_input(input).unwrap();
+        let ends = starts(&solution).into_iter().map(|start| ends(&solution, start)).collect_vec();
+        ends[0].iter().find(|steps| ends.iter().all(|other| other.binary_search(steps).is_ok())).map(|&steps| steps.into())
+    }
+
+    /// Whether the LCM holds: every ghost stands on an end exactly on the multiples of the steps to
+    /// its first one. It does not when a ghost takes longer to its first end than to go round its
+    /// loop, or passes several ends on the way round.
+    fn lcm_holds(input: &str) -> bool {
+        let solution = Solution::from_input(input).unwrap();
+        starts(&solution).into_iter().all(|start| {
+            let ends = ends(&solution, start);
+            ends.iter().enumerate().all(|(i, &steps)| steps == (i + 1) * ends[0]) && ends.len() == LIMIT / ends[0]
+        })
+    }
+
+    #[test]
+    fn matches_naive() {
+        let fast = |input: &String| Some(Solution::from_input(input).unwrap().part2());
+        let naive = |input: &String| naive_part2(input);
+
+        let inputs = (0..30)
+            .map(|seed| generate::network(&mut generate::rng(seed), 1 + seed as usize % 3, 1 + seed as usize % 4, 4))
+            .chain([EXAMPLE_3.to_string()]);
+        assert_agree(inputs, fast, naive);
+
+        // Off the puzzle's promise, the LCM may only go wrong where it does not hold, and does on some
+        let wandering = (0..40)
+            .map(|seed| {
+                let (rng, seed) = (&mut generate::rng(seed), seed as usize);
+                generate::wandering_network(rng, 1 + seed % 3, 1 + seed % 4, 4, seed % 5, 1 + seed % 2)
+            })
+            .collect_vec();
+        let (regular, irregular): (Vec<_>, Vec<_>) = wandering.into_iter().partition(|input| lcm_holds(input));
+        assert!(!regular.is_empty() && !irregular.is_empty());
+        assert_agree(regular, fast, naive);
+        assert!(!compare(irregular, fast, naive).is_empty());
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error(""), "");
+        assert_eq!(error("RX\n\nAAA = (AAA, AAA)"), "X");
+        assert_eq!(error("RL\n\nAAA = (BBB)"), "AAA = (BBB)");
+        assert_eq!(error("RL\n\nAAA = (AAA, BBB)\nZZZ = (ZZZ, ZZZ)"), "BBB");
+    }
+}
\ No newline at end of file
@@ -1,8 +1,8 @@
-use std::fs::read_to_string;
-use std::io::{self, Write};
-
 use itertools::Itertools;
 
+use crate::input::numbers;
+use crate::{Answer, ParseError, Solver};
+
 pub struct Solution {
     lines: Vec<String>,
     values: Vec<Vec<i64>>,
@@ -10,25 +10,43 @@ pub struct Solution {
 }
 
 impl Solution {
-    pub fn init() -> Self {
+    fn extrapolate_end(&self, derivates: &Vec<Vec<i64>>) -> i64 {
+        // Intregrate the result
+        let mut current_result = 0;
+        for derivate in derivates.iter().rev() {
+            current_result += derivate.last().unwrap();
+        }
+        current_result
+    }
+
+    fn extrapolate_start(&self, derivates: &Vec<Vec<i64>>) -> i64 {
+        // Intregrate the result
+        let mut current_result = 0;
+        for derivate in derivates.iter().rev() {
+            current_result = derivate.first().unwrap() - current_result;
+        }
+        current_result
+    }
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = 2023;
+    const DAY: usize = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
         let mut lines = Vec::new();
-        for line in read_to_string("inputs/day9.txt").unwrap().lines() {
+        for line in input.lines() {
             lines.push(line.to_string());
         }
 
         // Parse the values
-        let values = lines
-            .iter()
-            .map(|line| line  
-                                    .split_whitespace()
-                                    .map(|v| v.parse::<i64>().unwrap())
-                                    .collect_vec())
-            .collect_vec();
+        let values = input.lines().map(numbers::<i64>).collect::<Result<Vec<_>, _>>()?;
 
         
         // Compute the "derivates"
         let mut derivates: Vec<Vec<Vec<i64>>> = vec![];
-        for values_list in &values {
+        for (values_list, line) in values.iter().zip(input.lines()) {
             let mut current_derivates = vec![values_list.clone()];
             while current_derivates.last().unwrap().iter().any(|x| *x != 0) {
                 let next_order_derivates = current_derivates.last().unwrap()
@@ -38,66 +56,61 @@ impl Solution {
                     .collect_vec();
                 current_derivates.push(next_order_derivates);
             }
+            // Too few values to know the next ones, e.g. a single one
+            if current_derivates.last().unwrap().is_empty() {
+                return Err(ParseError::new("expected the differences to reach zero", line));
+            }
             derivates.push(current_derivates);
         }
 
-        Self {
+        Ok(Self {
             lines,
             values,
             derivates,
-        }
+        })
     }
 
-    fn part1(&mut self) -> i64 {
+    fn part1(&mut self) -> Answer {
         self
             .derivates
             .iter()
             .map(|derivate| self.extrapolate_end(derivate))
-            .sum()
+            .sum::<i64>().into()
     }
 
-    fn part2(&mut self) -> i64 {
+    fn part2(&mut self) -> Answer {
         self
             .derivates
             .iter()
             .map(|derivate| self.extrapolate_start(derivate))
-            .sum()
+            .sum::<i64>().into()
     }
+}
 
-    fn extrapolate_end(&self, derivates: &Vec<Vec<i64>>) -> i64 {
-        // Intregrate the result
-        let mut current_result = 0;
-        for derivate in derivates.iter().rev() {
-            current_result += derivate.last().unwrap();
-        }
-        current_result
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 114.into());
     }
 
-    fn extrapolate_start(&self, derivates: &Vec<Vec<i64>>) -> i64 {
-        // Intregrate the result
-        let mut current_result = 0;
-        for derivate in derivates.iter().rev() {
-            current_result = derivate.first().unwrap() - current_result;
-        }
-        current_result
+    #[test]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 2.into());
     }
 
-    pub fn solve(&mut self) {
-        println!("========= DAY 9 ========");
-        
-        print!("Solving part 1: ");
-        io::stdout().flush().unwrap();
-        let start = std::time::Instant::now();
-        let part1 = self.part1();
-        let part1_time = start.elapsed();
-        println!("{:?} (took {:?})", part1, part1_time);
-
-        print!("Solving part 2: ");
-        io::stdout().flush().unwrap();
-        let start = std::time::Instant::now();
-        let part2 = self.part2();
-        let part2_time = start.elapsed();
-        println!("{:?} (took {:?})", part2, part2_time);
-        println!();
+    #[test]
+    fn malformed() {
+        let error = |input| Solution::from_input(input).err().unwrap().text;
+        assert_eq!(error("0 3 6\n5"), "5");
+        assert_eq!(error("1 4 9"), "1 4 9");
+        assert_eq!(error("1 x"), "x");
     }
 }
\ No newline at end of file
@@ -0,0 +1,50 @@
+use crate::{Answer, ParseError, Solver};
+
+pub struct Solution {
+    lines: Vec<String>,
+}
+
+impl Solver for Solution {
+    const YEAR: u32 = REPLACEYEAR;
+    const DAY: usize = REPLACEDAY;
+    const TITLE: &'static str = "REPLACETITLE";
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+        for line in input.lines() {
+            lines.push(line.to_string());
+        }
+
+        Ok(Self {
+            lines,
+        })
+    }
+
+    fn part1(&mut self) -> Answer {
+        Answer::Skipped("not solved yet".to_string())
+    }
+
+    fn part2(&mut self) -> Answer {
+        Answer::Skipped("not solved yet".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn part1() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part1(), 0.into());
+    }
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn part2() {
+        assert_eq!(Solution::from_input(EXAMPLE).unwrap().part2(), 0.into());
+    }
+}